@@ -0,0 +1,129 @@
+use std::fmt;
+use std::io;
+
+pub enum Error {
+    // Could not read the input at all
+    Io(io::Error),
+    // Some part of the input did not have the expected format
+    Parse(ParseError),
+    // Several parts of the input did not (parsers that carry on past a bad line report every one)
+    Parses(Vec<ParseError>),
+    // The input was read fine but has no answer (e.g. no two box ids differ by one letter)
+    NoAnswer(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/*
+    Where and why a malformed input could not be parsed, e.g.
+
+    day3/input.txt:2:6: expected " @ "
+        #2 @291,191: 12x19
+             ^
+
+    Lines and columns start at 1, and columns count characters rather than bytes.
+    Parsers only see the input text, so whoever loaded the file fills in the file name (Error::in_file).
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, reason: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    // Points just past the last character of the input, for inputs that stop too early
+    pub fn end_of_input(input: &str, reason: &str) -> ParseError {
+        let last_line = input.lines().last().unwrap_or("");
+        let line = std::cmp::max(input.lines().count(), 1);
+        ParseError::new(line, last_line.chars().count() + 1, last_line, reason)
+    }
+}
+
+impl Error {
+    // Records which file the input came from, if this is a parse error
+    pub fn in_file(self, file: &str) -> Error {
+        match self {
+            Error::Parse(mut e) => {
+                e.file = Some(file.to_string());
+                Error::Parse(e)
+            }
+            Error::Parses(mut errors) => {
+                for e in &mut errors {
+                    e.file = Some(file.to_string());
+                }
+                Error::Parses(errors)
+            }
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        writeln!(f, "{}:{}:{}: {}", file, self.line, self.column, self.reason)?;
+        writeln!(f, "    {}", self.text)?;
+        write!(f, "    {}^", " ".repeat(self.column.saturating_sub(1)))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "could not read input: {}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Parses(errors) => {
+                for e in errors {
+                    writeln!(f, "{}", e)?;
+                }
+                write!(f, "{} lines could not be parsed", errors.len())
+            }
+            Error::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+// main() prints the Debug form of the error it returns: make that the readable diagnostic
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Parse(e)
+    }
+}
+
+// Every error found in the input: just a Parse error if there is only one
+impl From<Vec<ParseError>> for Error {
+    fn from(mut errors: Vec<ParseError>) -> Error {
+        if errors.len() == 1 {
+            Error::Parse(errors.remove(0))
+        } else {
+            Error::Parses(errors)
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use error::{Error, ParseError, Result};
+use log;
+
+// Reads the whole input file into a string (day5's polymer, day8's number stream)
+pub fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String> {
+    let mut input_string = String::new();
+    File::open(path)?.read_to_string(&mut input_string)?;
+    Ok(input_string)
+}
+
+// Iterates through each line in the input file, without the line endings
+pub fn lines<P: AsRef<Path>>(path: P) -> Result<impl Iterator<Item = Result<String>>> {
+    let f = File::open(path)?;
+    Ok(BufReader::new(f).lines().map(|line| line.map_err(From::from)))
+}
+
+// Collects every line of the input file for further processing
+pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    lines(path)?.collect()
+}
+
+// Opens an input file ("-" is stdin) to be read a line at a time, for inputs too big to read whole.
+// Errors name the file
+pub fn open(path: &str) -> Result<Box<dyn BufRead>> {
+    if path == "-" {
+        return Ok(Box::new(BufReader::new(io::stdin())));
+    }
+    let file = File::open(path).map_err(|e| in_file(path, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn in_file(path: &str, e: io::Error) -> Error {
+    Error::Io(io::Error::new(e.kind(), format!("{}: {}", display_name(path), e)))
+}
+
+/*
+    The input for one run, read from any number of files and/or stdin ("-") and joined in order
+
+    Parsers only see the joined text, so line numbers in their errors count from the start of the
+    first file. Sources::locate turns those back into the file and line the text came from.
+*/
+pub struct Sources {
+    pub text: String,
+    files: Files,
+}
+
+impl Sources {
+    pub fn load(paths: &[String]) -> Result<Sources> {
+        let mut sources = Sources {
+            text: String::new(),
+            files: Files::new(),
+        };
+        for path in paths {
+            let mut text = String::new();
+            open(path)?.read_to_string(&mut text).map_err(|e| in_file(path, e))?;
+            sources.push(path, &text);
+        }
+        Ok(sources)
+    }
+
+    // For the single-day binaries: the files named on the command line, or default when there are none
+    // (also applies any log level flags)
+    pub fn from_args(default: String) -> Result<Sources> {
+        let mut paths = log::init_from_args();
+        if paths.is_empty() {
+            paths.push(default);
+        }
+        Sources::load(&paths)
+    }
+
+    fn push(&mut self, path: &str, text: &str) {
+        // Keep the last line of one file and the first line of the next apart
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+        self.files.push(path, self.text.lines().count());
+        self.text.push_str(text);
+    }
+
+    // The files the input came from, for messages
+    pub fn names(&self) -> String {
+        self.files.names()
+    }
+
+    // Points a parse error at the file (and line within it) the offending text came from
+    pub fn locate(&self, e: Error) -> Error {
+        match e {
+            Error::Parse(e) => Error::Parse(self.locate_one(e)),
+            Error::Parses(errors) => Error::Parses(errors.into_iter().map(|e| self.locate_one(e)).collect()),
+            e => e,
+        }
+    }
+
+    fn locate_one(&self, mut e: ParseError) -> ParseError {
+        if let Some((name, line)) = self.files.locate(e.line) {
+            e.file = Some(name.to_string());
+            e.line = line;
+        }
+        e
+    }
+}
+
+/*
+    Which file each line of several files read one after the other came from: Sources keeps one for
+    its joined text, and inputs streamed through open can keep their own
+*/
+#[derive(Debug, Clone, Default)]
+pub struct Files {
+    // (file name, number of lines before this file)
+    files: Vec<(String, usize)>,
+}
+
+impl Files {
+    pub fn new() -> Files {
+        Files::default()
+    }
+
+    // The next file, which starts after lines_before lines of the files before it
+    pub fn push(&mut self, path: &str, lines_before: usize) {
+        self.files.push((display_name(path), lines_before));
+    }
+
+    pub fn names(&self) -> String {
+        let names: Vec<&str> = self.files.iter().map(|(name, _)| name.as_str()).collect();
+        names.join(", ")
+    }
+
+    // The file and the line within it of a line (counting from 1) of all the files together.
+    // None when there are no files
+    pub fn locate(&self, line: usize) -> Option<(&str, usize)> {
+        self.files
+            .iter()
+            .rev()
+            .find(|(_, before)| *before < line)
+            .or_else(|| self.files.first())
+            .map(|(name, before)| (name.as_str(), line - before))
+    }
+}
+
+fn display_name(path: &str) -> String {
+    if path == "-" {
+        "<stdin>".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+// A checked-in input: name relative to the current directory if it is there, otherwise relative to dir
+// (a crate passes its env!("CARGO_MANIFEST_DIR") so that it can be run from anywhere)
+pub fn default_path(name: &str, dir: &str) -> String {
+    if Path::new(name).exists() {
+        name.to_string()
+    } else {
+        Path::new(dir).join(name).to_string_lossy().into_owned()
+    }
+}
@@ -0,0 +1,26 @@
+/*
+    Shared code for the AOC 2018 solutions
+
+    Every day used to carry its own copy of the File::open + BufReader::lines() + unwrap() loop.
+    This crate owns that instead:
+    - input: loading an input file whole or line by line
+    - parse: turning lines / whitespace separated streams into numbers
+    - error: the error type every day returns instead of panicking
+    - solution: the Solution trait every day implements, and its Answer type
+    - json: writing answers and their extras out as JSON for scripts
+    - random: the seeded random number generator behind the input generators
+    - log: the quiet / answer / info / trace levels, and the answer!/info!/trace! macros
+
+*/
+
+mod error;
+pub mod log;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod random;
+pub mod solution;
+
+pub use error::{Error, ParseError, Result};
+pub use json::Json;
+pub use solution::{Answer, Extras, Implementation, Solution};
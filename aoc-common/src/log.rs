@@ -0,0 +1,121 @@
+use std::env;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*
+    How much every day prints, from least to most:
+    - Quiet: nothing but errors
+    - Answer: just the answers (the default)
+    - Info: plus a summary of how each answer was reached (the chosen guard, the matching box ids, ...)
+    - Trace: plus every intermediate step (every log entry, every sweep line, every edge, ...)
+
+    Answers go to stdout, info and trace go to stderr so that the answers can still be piped somewhere.
+    Print through the answer!/info!/trace! macros rather than println!.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Answer,
+    Info,
+    Trace,
+}
+
+pub const FLAGS_USAGE: &str =
+    "-q/--quiet, -v/--verbose (info), -vv (trace) or --log <quiet|answer|info|trace>";
+
+static LEVEL: AtomicUsize = AtomicUsize::new(Level::Answer as usize);
+
+impl Level {
+    pub fn from_name(name: &str) -> Option<Level> {
+        match name {
+            "quiet" => Some(Level::Quiet),
+            "answer" => Some(Level::Answer),
+            "info" => Some(Level::Info),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as usize, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Answer,
+        2 => Level::Info,
+        _ => Level::Trace,
+    }
+}
+
+// Whether messages at this level are currently printed
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+// Sets the level from any verbosity flags in args, returning the remaining arguments
+pub fn take_flags(args: &[String]) -> Result<Vec<String>, String> {
+    let mut rest: Vec<String> = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-q" | "--quiet" => set_level(Level::Quiet),
+            "-v" | "--verbose" => set_level(Level::Info),
+            "-vv" => set_level(Level::Trace),
+            "--log" | "-l" => {
+                let name = args.next().ok_or("missing value for --log")?;
+                let level = Level::from_name(name)
+                    .ok_or_else(|| format!("unknown log level {}", name))?;
+                set_level(level);
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+    Ok(rest)
+}
+
+// For the single-day binaries: sets the level from the command line and returns the input paths
+// given after the flags ("-" meaning stdin)
+pub fn init_from_args() -> Vec<String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = take_flags(&args).and_then(|rest| match rest.iter().find(|a| a.starts_with('-') && *a != "-") {
+        Some(flag) => Err(format!("unknown flag {}", flag)),
+        None => Ok(rest),
+    });
+    match result {
+        Ok(paths) => paths,
+        Err(e) => {
+            eprintln!("{}\n\nUsage: [options] [FILE|-]...\nOptions: {}", e, FLAGS_USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! answer {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Answer) {
+            println!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use error::{ParseError, Result};
+
+/*
+    Walks through one line of input, keeping track of the column so that anything
+    unexpected turns into a ParseError pointing at the offending character
+
+    Scanner::new("#1 @ 3,2: 5x4", 1) then literal("#"), int(), literal(" @ "), ...
+*/
+pub struct Scanner<'a> {
+    text: &'a str,
+    line: usize,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    // line is the 1-based line number of text within the input
+    pub fn new(text: &'a str, line: usize) -> Scanner<'a> {
+        Scanner { text, line, pos: 0 }
+    }
+
+    // 1-based column (in characters) of the current position
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    // A parse error at the current position
+    pub fn error(&self, reason: &str) -> ParseError {
+        ParseError::new(self.line, self.column(), self.text, reason)
+    }
+
+    // Byte offset of the current position: cheaper to keep than column() when remembering many positions
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    // Consumes exactly the given text
+    pub fn literal(&mut self, expected: &str) -> Result<()> {
+        if self.rest().starts_with(expected) {
+            self.pos += expected.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected \"{}\"", expected)).into())
+        }
+    }
+
+    // Consumes the given text if it is next, returning whether it was there
+    pub fn optional(&mut self, expected: &str) -> bool {
+        self.literal(expected).is_ok()
+    }
+
+    // Consumes everything up to the next whitespace (or the end of the line)
+    pub fn word(&mut self) -> Result<&'a str> {
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a word").into());
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    // Consumes any single character
+    pub fn char(&mut self) -> Result<char> {
+        match self.rest().chars().next() {
+            Some(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            None => Err(self.error("unexpected end of line").into()),
+        }
+    }
+
+    // Consumes an integer with an optional sign, e.g. 12, +3 or -14
+    pub fn int<T: FromStr>(&mut self) -> Result<T> {
+        let rest = self.rest();
+        let sign_len = if rest.starts_with('+') || rest.starts_with('-') { 1 } else { 0 };
+        let digits_len = rest[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign_len);
+        if digits_len == 0 {
+            return Err(self.error("expected an integer").into());
+        }
+        match rest[..sign_len + digits_len].parse() {
+            Ok(n) => {
+                self.pos += sign_len + digits_len;
+                Ok(n)
+            }
+            Err(_) => Err(self.error("integer out of range").into()),
+        }
+    }
+
+    // Fails unless the whole line has been consumed
+    pub fn end(&self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected text at end of line").into())
+        }
+    }
+}
+
+// Parses a line holding a single integer, e.g. "+13" or "-4"
+// line is only used to report where a bad number came from
+pub fn int<T: FromStr>(text: &str, line: usize) -> Result<T> {
+    let mut scanner = Scanner::new(text, line);
+    let n = scanner.int()?;
+    scanner.end()?;
+    Ok(n)
+}
+
+// One integer per line, as in the day 1 frequency changes
+pub fn ints_per_line<T: FromStr>(text: &str) -> Result<Vec<T>> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| int(line, i + 1))
+        .collect()
+}
+
+// A stream of integers separated by any whitespace (spaces or newlines), as in day 8
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>> {
+    let mut nums: Vec<T> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let mut scanner = Scanner::new(line, i + 1);
+        scanner.skip_whitespace();
+        while !scanner.is_empty() {
+            nums.push(scanner.int()?);
+            if !scanner.is_empty() && !scanner.rest().starts_with(char::is_whitespace) {
+                return Err(scanner.error("expected whitespace between numbers").into());
+            }
+            scanner.skip_whitespace();
+        }
+    }
+    Ok(nums)
+}
@@ -0,0 +1,84 @@
+/*
+    A small seeded random number generator for the input generators (aoc2018 generate)
+
+    The same seed always gives the same input, on every platform, so generated inputs can be
+    reproduced from just the command line. This is splitmix64: fast, and plenty random for
+    test inputs, but not for anything that needs to be unpredictable.
+*/
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, n), for n > 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        // Reject the top sliver of values that would make some results more likely than others
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    // Uniform in [lo, hi], both inclusive
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo) as u64 + 1) as i64
+    }
+
+    // Uniform in [0, 1)
+    pub fn float(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // True with probability p
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.float() < p
+    }
+
+    // A uniformly random element, for a non-empty slice
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    // A uniformly random lowercase letter
+    pub fn lowercase(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+
+    // Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+pub const DEFAULT_SEED: u64 = 2018;
+
+/*
+    What to generate: every day has its own idea of size (claims for day 3, shifts for day 4,
+    units for day 5, ...) and its own default close to the real input. Only day 5 uses density.
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Options {
+    pub size: Option<usize>,
+    pub density: Option<f64>,
+}
+
+// Generates a puzzle input as text
+pub type Generator = fn(&mut Rng, &Options) -> String;
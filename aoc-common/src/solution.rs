@@ -0,0 +1,100 @@
+use std::fmt;
+
+use error::Result;
+use json::Json;
+
+// The answer to one part of a day: most days produce a number, some a string (e.g. day 7's step order)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer {
+        Answer::Int(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Answer {
+        Answer::Int(i64::from(n))
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Answer {
+        Answer::Int(i64::from(n))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Int(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl<'a> From<&'a str> for Answer {
+    fn from(s: &'a str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+// Named values beyond the answer that explain it, e.g. day 4's chosen guard and minute
+pub type Extras = Vec<(&'static str, Json)>;
+
+/*
+    Every day implements this so its solvers can be called from other code (the aoc2018 runner)
+    instead of only printing from main()
+
+    The input is parsed once and shared between both parts
+*/
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    // Extras for the JSON output of a part. Most days have none
+    fn extras(_input: &Self::Input, _part: u32) -> Result<Extras> {
+        Ok(Vec::new())
+    }
+}
+
+// Parse the puzzle input and solve part 1
+pub fn part1<S: Solution>(input: &str) -> Result<Answer> {
+    S::part1(&S::parse(input)?)
+}
+
+// Parse the puzzle input and solve part 2
+pub fn part2<S: Solution>(input: &str) -> Result<Answer> {
+    S::part2(&S::parse(input)?)
+}
+
+// Parse the puzzle input and collect the extras for a part
+pub fn extras<S: Solution>(input: &str, part: u32) -> Result<Extras> {
+    S::extras(&S::parse(input)?, part)
+}
+
+// One named way of solving a part, so that alternative approaches to a day can be compared
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: u32,
+    pub solve: fn(&str) -> Result<Answer>,
+}
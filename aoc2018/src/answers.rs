@@ -0,0 +1,45 @@
+use aoc_common::parse::Scanner;
+use aoc_common::{input, Result};
+
+// The checked-in regression answers, relative to the repository root
+pub const ANSWERS_FILENAME: &str = "answers.txt";
+
+// A known-good answer for one part of a day on one input file
+pub struct Expected {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+/*
+    One answer per line: day part input answer
+    e.g. 7 1 day7/input.2.txt CABDFE
+
+    Blank lines and lines starting with # are skipped
+*/
+pub fn parse(text: &str) -> Result<Vec<Expected>> {
+    let mut expected: Vec<Expected> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut scanner = Scanner::new(line, i + 1);
+        let day = scanner.int()?;
+        scanner.skip_whitespace();
+        let part = scanner.int()?;
+        scanner.skip_whitespace();
+        let input = scanner.word()?.to_string();
+        scanner.skip_whitespace();
+        let answer = scanner.rest().trim_end();
+        if answer.is_empty() {
+            return Err(scanner.error("expected an answer").into());
+        }
+        expected.push(Expected { day, part, input, answer: answer.to_string() });
+    }
+    Ok(expected)
+}
+
+pub fn load(path: &str) -> Result<Vec<Expected>> {
+    parse(&input::read_to_string(path)?).map_err(|e| e.in_file(path))
+}
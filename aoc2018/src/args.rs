@@ -0,0 +1,346 @@
+use aoc_common::random::Options;
+use realday2::edit::{self, Distance};
+use realday2::external;
+
+pub const USAGE: &str = "Usage:
+    aoc2018 run --day <N> [--part <1|2>] [--input <PATH|->]... [--impl <NAME>] [--json]
+    aoc2018 verify [--day <N>] [--answers <PATH>]
+    aoc2018 bench --day <N> [--part <1|2>] [--input <PATH|->]... [--runs <N>]
+    aoc2018 reach [--targets <LIST>]... [--targets-file <PATH>]... [--input <PATH|->]... [--json]
+    aoc2018 similar --id <ID>... [--within <D> | --nearest <K>] [--metric <NAME>] [--input <PATH|->]... [--json]
+    aoc2018 lookup [--prefix <TEXT>]... [--pattern <TEXT>]... [--input <PATH|->]... [--json]
+    aoc2018 pairs [--input <PATH|->]... [--memory <MiB>] [--json]
+    aoc2018 generate --day <N> [--size <N>] [--seed <N>] [--density <0-1>]
+    aoc2018 list
+
+Every command also takes -q/--quiet, -v/--verbose (info), -vv (trace) or --log <quiet|answer|info|trace>.
+
+Without --part both parts are run. Without --input the day's checked-in input.txt is used.
+--input - reads stdin, and several --input files are joined in order into one input.
+--impl runs one of the day's named implementations (see list) instead of its usual one, in the parts that
+have one by that name unless --part is given.
+With --json run prints one JSON object per part: day, part, input, answer, time_ms and the day's extras.
+verify checks every answer recorded in answers.txt (or --answers) for every day (or --day).
+reach answers when each target frequency is first reached by day 1's calibration, if ever.
+Targets are written like the changes themselves, e.g. --targets \"5, -3, +100\".
+similar looks up catalogue box ids (day 2's input by default) that differ from each --id in at most
+--within edits (default 1), or the --nearest K of them, closest first. --metric is hamming (the default,
+differing positions), levenshtein or damerau; the last two also take catalogues of ids of any length.
+lookup lists the catalogue box ids starting with each --prefix or matching each whole --pattern, where
+? stands for any one character (e.g. --pattern \"abc?efg\"), and the characters they all share.
+pairs lists every two box ids differing in exactly one position, for id files too big for memory: it
+sorts in temporary files (under TMPDIR) and keeps to about --memory MiB (default 64).
+generate prints a random input for the day (the same one for the same seed). What --size counts
+depends on the day: changes, box ids, claims, shifts, polymer units, steps or tree nodes.
+--density is the chance that each day 5 polymer unit reacts with the one before it.
+bench times every implementation of the day's parts over --runs runs (default 10).";
+
+pub const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug)]
+pub enum Command {
+    Run {
+        day: u32,
+        part: Option<u32>,
+        input: Vec<String>,
+        implementation: Option<String>,
+        json: bool,
+    },
+    Verify {
+        day: Option<u32>,
+        answers: Option<String>,
+    },
+    Bench {
+        day: u32,
+        part: Option<u32>,
+        input: Vec<String>,
+        runs: usize,
+    },
+    Reach {
+        targets: Vec<String>,
+        targets_files: Vec<String>,
+        input: Vec<String>,
+        json: bool,
+    },
+    Similar {
+        ids: Vec<String>,
+        query: Similarity,
+        metric: Distance,
+        input: Vec<String>,
+        json: bool,
+    },
+    Lookup {
+        queries: Vec<Lookup>,
+        input: Vec<String>,
+        json: bool,
+    },
+    Pairs {
+        input: Vec<String>,
+        memory: usize,
+        json: bool,
+    },
+    Generate {
+        day: u32,
+        options: Options,
+        seed: Option<u64>,
+    },
+    List,
+}
+
+#[derive(Debug)]
+pub enum Similarity {
+    Within(u32),
+    Nearest(usize),
+}
+
+#[derive(Debug)]
+pub enum Lookup {
+    Prefix(String),
+    Pattern(String),
+}
+
+// Parses the arguments after the program name
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let (command, flags) = match args.split_first() {
+        Some((command, flags)) => (command.as_str(), flags),
+        None => return Err("no command given".to_string()),
+    };
+
+    match command {
+        "run" => {
+            let (mut day, mut part, mut input, mut implementation, mut json) = (None, None, Vec::new(), None, false);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" | "-d" => day = Some(number(flag, value)?),
+                    "--part" | "-p" => part = Some(number(flag, value)?),
+                    "--input" | "-i" => input.push(value.clone()),
+                    "--impl" => implementation = Some(value.clone()),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            let day = day.ok_or("run needs --day")?;
+            if let Some(part) = part {
+                if part != 1 && part != 2 {
+                    return Err(format!("there is no part {}", part));
+                }
+            }
+            Ok(Command::Run {
+                day,
+                part,
+                input,
+                implementation,
+                json,
+            })
+        }
+        "verify" => {
+            let (mut day, mut answers) = (None, None);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" | "-d" => day = Some(number(flag, value)?),
+                    "--answers" | "-a" => answers = Some(value.clone()),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            Ok(Command::Verify { day, answers })
+        }
+        "bench" => {
+            let (mut day, mut part, mut input, mut runs) = (None, None, Vec::new(), DEFAULT_RUNS);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" | "-d" => day = Some(number(flag, value)?),
+                    "--part" | "-p" => part = Some(number(flag, value)?),
+                    "--input" | "-i" => input.push(value.clone()),
+                    "--runs" | "-r" => runs = number(flag, value)? as usize,
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            let day = day.ok_or("bench needs --day")?;
+            if let Some(part) = part {
+                if part != 1 && part != 2 {
+                    return Err(format!("there is no part {}", part));
+                }
+            }
+            if runs == 0 {
+                return Err("--runs must be at least 1".to_string());
+            }
+            Ok(Command::Bench {
+                day,
+                part,
+                input,
+                runs,
+            })
+        }
+        "reach" => {
+            let (mut targets, mut targets_files, mut input, mut json) = (Vec::new(), Vec::new(), Vec::new(), false);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--targets" | "-t" => targets.push(value.clone()),
+                    "--targets-file" => targets_files.push(value.clone()),
+                    "--input" | "-i" => input.push(value.clone()),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            if targets.is_empty() && targets_files.is_empty() {
+                return Err("reach needs --targets or --targets-file".to_string());
+            }
+            Ok(Command::Reach {
+                targets,
+                targets_files,
+                input,
+                json,
+            })
+        }
+        "similar" => {
+            let (mut ids, mut query, mut metric, mut input, mut json) =
+                (Vec::new(), None, Distance::Hamming, Vec::new(), false);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                let similarity = match flag.as_str() {
+                    "--id" => {
+                        ids.push(value.clone());
+                        continue;
+                    }
+                    "--input" | "-i" => {
+                        input.push(value.clone());
+                        continue;
+                    }
+                    "--metric" | "-m" => {
+                        metric = Distance::from_name(value).ok_or_else(|| {
+                            format!("unknown metric {} (expected one of {})", value, edit::DISTANCE_NAMES.join(", "))
+                        })?;
+                        continue;
+                    }
+                    "--within" | "-w" => Similarity::Within(number(flag, value)?),
+                    "--nearest" | "-k" => Similarity::Nearest(number(flag, value)? as usize),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                };
+                if query.is_some() {
+                    return Err("similar takes one of --within or --nearest".to_string());
+                }
+                query = Some(similarity);
+            }
+            if ids.is_empty() {
+                return Err("similar needs --id".to_string());
+            }
+            Ok(Command::Similar {
+                ids,
+                query: query.unwrap_or(Similarity::Within(1)),
+                metric,
+                input,
+                json,
+            })
+        }
+        "lookup" => {
+            let (mut queries, mut input, mut json) = (Vec::new(), Vec::new(), false);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--prefix" => queries.push(Lookup::Prefix(value.clone())),
+                    "--pattern" => queries.push(Lookup::Pattern(value.clone())),
+                    "--input" | "-i" => input.push(value.clone()),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            if queries.is_empty() {
+                return Err("lookup needs --prefix or --pattern".to_string());
+            }
+            Ok(Command::Lookup { queries, input, json })
+        }
+        "pairs" => {
+            let (mut input, mut memory, mut json) = (Vec::new(), external::DEFAULT_BUDGET >> 20, false);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                if flag == "--json" {
+                    json = true;
+                    continue;
+                }
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--input" | "-i" => input.push(value.clone()),
+                    "--memory" | "-m" => memory = number(flag, value)? as usize,
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            if memory == 0 {
+                return Err("--memory must be at least 1".to_string());
+            }
+            Ok(Command::Pairs { input, memory, json })
+        }
+        "generate" => {
+            let (mut day, mut options, mut seed) = (None, Options::default(), None);
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                let value = flags
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" | "-d" => day = Some(number(flag, value)?),
+                    "--size" | "-n" => options.size = Some(number(flag, value)? as usize),
+                    "--seed" | "-s" => {
+                        seed = Some(value.parse().map_err(|_| format!("{} expects a number, got {}", flag, value))?)
+                    }
+                    "--density" => {
+                        let density: f64 = value
+                            .parse()
+                            .map_err(|_| format!("{} expects a number, got {}", flag, value))?;
+                        if !(0.0..=1.0).contains(&density) {
+                            return Err(format!("{} must be between 0 and 1", flag));
+                        }
+                        options.density = Some(density);
+                    }
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            let day = day.ok_or("generate needs --day")?;
+            Ok(Command::Generate { day, options, seed })
+        }
+        "list" => Ok(Command::List),
+        _ => Err(format!("unknown command {}", command)),
+    }
+}
+
+fn number(flag: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got {}", flag, value))
+}
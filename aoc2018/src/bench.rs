@@ -0,0 +1,47 @@
+use std::time::{Duration, Instant};
+
+use alloc;
+use aoc_common::Result;
+use registry::Day;
+use verify::millis;
+
+/*
+    Runs every named implementation of a part against the same input, runs times each,
+    and reports the min/median/max wall time and the allocations per run
+
+    The time includes parsing the input, since each implementation is free to parse differently
+*/
+pub fn bench(solved: &Day, part: u32, input_string: &str, runs: usize) -> Result<()> {
+    answer!("Day {} part {}, {} runs", solved.day, part, runs);
+    for (name, solve) in solved.implementations(part) {
+        let mut times: Vec<Duration> = Vec::with_capacity(runs);
+        let (allocs_before, bytes_before) = alloc::allocated();
+        let mut answer = None;
+        for _ in 0..runs {
+            let timer = Instant::now();
+            let result = solve(input_string);
+            times.push(timer.elapsed());
+            answer = Some(result);
+        }
+        let (allocs_after, bytes_after) = alloc::allocated();
+
+        let answer = match answer {
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(e)) => format!("error ({})", e.to_string().lines().next().unwrap_or("")),
+            None => "-".to_string(),
+        };
+        times.sort();
+        let runs_made = std::cmp::max(times.len(), 1);
+        answer!(
+            "  {:<16} min {:>12}  median {:>12}  max {:>12}  {:>10} allocs {:>12} bytes per run  answer {}",
+            name,
+            times.first().map(|t| millis(*t)).unwrap_or_default(),
+            times.get(times.len() / 2).map(|t| millis(*t)).unwrap_or_default(),
+            times.last().map(|t| millis(*t)).unwrap_or_default(),
+            (allocs_after - allocs_before) / runs_made,
+            (bytes_after - bytes_before) / runs_made,
+            answer
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use aoc_common::{Json, Result};
+use args::Lookup;
+use realday2::catalogue::Catalogue;
+use registry;
+
+/*
+    Lists the catalogue box ids (day 2's input by default) starting with each prefix or matching each
+    whole pattern, and the characters they all share
+*/
+pub fn lookup(queries: &[Lookup], input: Vec<String>, json: bool) -> Result<()> {
+    let solved = registry::find(2).unwrap();
+    let sources = solved.load_input(input)?;
+    let boxids = realday2::parse_any_boxids(&sources.text).map_err(|e| sources.locate(e))?;
+    let catalogue = Catalogue::new(&boxids);
+    for query in queries {
+        let (kind, text, found) = match query {
+            Lookup::Prefix(prefix) => ("prefix", prefix, catalogue.with_prefix(prefix)),
+            Lookup::Pattern(pattern) => ("pattern", pattern, catalogue.matching(pattern)),
+        };
+        let ids: Vec<&str> = found.iter().map(|&index| catalogue.id(index)).collect();
+        let common = realday2::common_characters_of(&ids);
+        if json {
+            let matches = found
+                .iter()
+                .map(|&index| Json::object(vec![("id", catalogue.id(index).into()), ("line", (index + 1).into())]))
+                .collect();
+            answer!(
+                "{}",
+                Json::object(vec![
+                    (kind, text.as_str().into()),
+                    ("matches", Json::Array(matches)),
+                    ("common", common.into()),
+                ])
+            );
+        } else {
+            answer!("{} {}: {} ids, common characters {}", kind, text, found.len(), common);
+            for index in found {
+                answer!("    {} (line {})", catalogue.id(index), index + 1);
+            }
+        }
+    }
+    Ok(())
+}
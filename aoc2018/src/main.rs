@@ -0,0 +1,222 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate day1;
+extern crate day2;
+extern crate day3;
+extern crate day4;
+extern crate day5;
+extern crate day7;
+extern crate day8;
+extern crate realday2;
+
+mod alloc;
+mod answers;
+mod args;
+mod bench;
+mod lookup;
+mod pairs;
+mod reach;
+mod registry;
+mod similar;
+mod verify;
+
+use aoc_common::random::{self, Rng};
+use aoc_common::{input, log, Json, Result};
+use args::Command;
+use std::env;
+use std::process;
+use std::time::Instant;
+
+// Counts allocations so that bench can report them
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+/*
+    Single entry point for every solved day
+
+    aoc2018 run --day 3 --part 2 --input day3/input_qn.txt
+    aoc2018 run --day 4 --json
+    aoc2018 run --day 1 --input - < changes.txt
+    aoc2018 verify
+    aoc2018 bench --day 5 --runs 20
+    aoc2018 reach --targets "0, 423, -1000"
+    aoc2018 similar --id fghij --nearest 3 --metric damerau
+    aoc2018 lookup --prefix qcs --pattern "abc?efg"
+    aoc2018 pairs --input huge_ids.txt --memory 256
+    aoc2018 generate --day 3 --size 5000 --seed 7 > claims.txt
+
+    Each day is looked up in the registry, which points at the part1/part2 functions
+    exported by that day's library crate.
+*/
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match log::take_flags(&args).and_then(|args| args::parse(&args)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, args::USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = run(command) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            implementation,
+            json,
+        } => {
+            let solved = match registry::find(day) {
+                Some(solved) => solved,
+                None => {
+                    eprintln!("Day {} is not solved (see aoc2018 list)", day);
+                    process::exit(2);
+                }
+            };
+            let sources = solved.load_input(input)?;
+
+            let mut parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            // Without --part, --impl picks the parts that have an implementation of that name, so that
+            // nothing fails half way through
+            if let Some(ref name) = implementation {
+                let has = |part: &u32| solved.implementations(*part).iter().any(|(n, _)| n == name);
+                if part.is_none() {
+                    parts.retain(has);
+                }
+                if !parts.iter().all(has) || parts.is_empty() {
+                    let which = part.map_or("either part".to_string(), |part| format!("part {}", part));
+                    eprintln!("Day {} has no implementation of {} called {} (see aoc2018 list)", day, which, name);
+                    process::exit(2);
+                }
+            }
+            // With --json a failed part is reported in its object like any other, and the rest still run
+            let mut failed = false;
+            for part in parts {
+                let solver = match implementation {
+                    // Checked above
+                    Some(ref name) => solved.implementations(part).into_iter().find(|(n, _)| n == name).unwrap().1,
+                    // args only lets parts 1 and 2 through
+                    None => solved.part(part).unwrap(),
+                };
+                let timer = Instant::now();
+                let result = solver(&sources.text).map_err(|e| sources.locate(e));
+                let elapsed = timer.elapsed();
+                if json {
+                    answer!("{}", json_report(solved, part, &sources, &result, elapsed.as_secs_f64()));
+                    failed |= result.is_err();
+                } else {
+                    answer!("Day {} part {}: {}", day, part, result?);
+                }
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Verify { day, answers } => {
+            let path = answers.unwrap_or_else(|| registry::repo_path(answers::ANSWERS_FILENAME));
+            let expected = answers::load(&path)?;
+            if verify::verify(&expected, day) > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            input,
+            runs,
+        } => {
+            let solved = match registry::find(day) {
+                Some(solved) => solved,
+                None => {
+                    eprintln!("Day {} is not solved (see aoc2018 list)", day);
+                    process::exit(2);
+                }
+            };
+            let sources = solved.load_input(input)?;
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            for part in parts {
+                bench::bench(solved, part, &sources.text, runs)?;
+            }
+        }
+        Command::Reach {
+            targets,
+            targets_files,
+            input,
+            json,
+        } => reach::reach(&targets, &targets_files, input, json)?,
+        Command::Similar {
+            ids,
+            query,
+            metric,
+            input,
+            json,
+        } => similar::similar(&ids, query, metric, input, json)?,
+        Command::Lookup { queries, input, json } => lookup::lookup(&queries, input, json)?,
+        Command::Pairs { input, memory, json } => pairs::pairs(input, memory, json)?,
+        Command::Generate { day, options, seed } => {
+            let solved = match registry::find(day) {
+                Some(solved) => solved,
+                None => {
+                    eprintln!("Day {} is not solved (see aoc2018 list)", day);
+                    process::exit(2);
+                }
+            };
+            // The generated input is the output, whatever the log level
+            print!("{}", (solved.generator)(&mut Rng::new(seed.unwrap_or(random::DEFAULT_SEED)), &options));
+        }
+        Command::List => {
+            for solved in registry::DAYS {
+                println!("Day {} ({})", solved.day, solved.crate_name);
+                for part in 1..=2 {
+                    let names: Vec<&str> = solved.implementations(part).iter().map(|(name, _)| *name).collect();
+                    if names.len() > 1 {
+                        println!("    part {}: {}", part, names.join(", "));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// The JSON object printed by run --json for one part. A part that failed gets an "error" instead
+// of an answer, so that scripts always get one object per part
+fn json_report(
+    solved: &registry::Day,
+    part: u32,
+    sources: &input::Sources,
+    result: &Result<aoc_common::Answer>,
+    seconds: f64,
+) -> Json {
+    let mut fields: Vec<(&str, Json)> = vec![
+        ("day", solved.day.into()),
+        ("part", part.into()),
+        ("input", sources.names().into()),
+    ];
+    match result {
+        Ok(answer) => {
+            fields.push(("answer", answer.into()));
+            fields.push(("time_ms", ((seconds * 100_000.0).round() / 100.0).into()));
+            // The answer was already found, so the extras can only fail on the same input for the same reason
+            if let Ok(extras) = (solved.extras)(&sources.text, part) {
+                fields.extend(extras);
+            }
+        }
+        Err(e) => fields.push(("error", e.to_string().into())),
+    }
+    Json::object(fields)
+}
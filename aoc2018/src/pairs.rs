@@ -0,0 +1,48 @@
+use aoc_common::input::{self, Files};
+use aoc_common::{Json, Result};
+use realday2::external::Finder;
+use registry;
+
+/*
+    Lists every two box ids differing in exactly one position, for id files too big for memory
+
+    The ids are streamed from each file in turn, never loaded whole (see realday2's external), and
+    the pairs are reported by file and line like any other input's errors (see input::Files)
+*/
+pub fn pairs(input: Vec<String>, memory: usize, json: bool) -> Result<()> {
+    let paths = registry::find(2).unwrap().input_paths(input);
+    let mut finder = Finder::new(memory << 20);
+    let mut files = Files::new();
+    for path in &paths {
+        files.push(path, finder.ids());
+        finder.add(input::open(path)?)?;
+    }
+    // Ids are numbered from 0, lines from 1
+    let at = |id: usize| files.locate(id + 1).unwrap();
+    let stats = finder.finish(|pair, first, second| {
+        let ((first_file, first_line), (second_file, second_line)) = (at(pair.first), at(pair.second));
+        if json {
+            let side = |file: &str, line: usize, id: &str| {
+                Json::object(vec![("file", file.into()), ("line", line.into()), ("id", id.into())])
+            };
+            answer!(
+                "{}",
+                Json::object(vec![
+                    ("position", pair.position.into()),
+                    ("first", side(first_file, first_line, first)),
+                    ("second", side(second_file, second_line, second)),
+                ])
+            );
+        } else {
+            answer!(
+                "position {}: {}:{} {} / {}:{} {}",
+                pair.position, first_file, first_line, first, second_file, second_line, second
+            );
+        }
+    })?;
+    info!(
+        "{} pairs among {} ids ({} records, {} run files)",
+        stats.pairs, stats.ids, stats.records, stats.runs
+    );
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use aoc_common::{input, Json, Result};
+use day2::reach::Reach;
+use registry;
+
+/*
+    Answers when each target frequency is first reached by day 1's calibration, if ever
+
+    Targets come from --targets lists and --targets-file files, written like the changes themselves
+*/
+pub fn reach(targets: &[String], targets_files: &[String], input: Vec<String>, json: bool) -> Result<()> {
+    // Targets use the same grammar as the changes
+    let mut target_values: Vec<i64> = Vec::new();
+    for list in targets {
+        target_values.extend(day1::parse_changes(list).map_err(|e| e.in_file("--targets"))?.values);
+    }
+    let target_files = input::Sources::load(targets_files)?;
+    target_values.extend(
+        day1::parse_changes(&target_files.text)
+            .map_err(|e| target_files.locate(e))?
+            .values,
+    );
+
+    // reach is only defined for day 1
+    let solved = registry::find(1).unwrap();
+    let sources = solved.load_input(input)?;
+    let changes = day1::parse_changes(&sources.text).map_err(|e| sources.locate(e))?;
+    let reached = day2::reach::first_reached(&changes, &target_values).map_err(|e| sources.locate(e))?;
+    for (target, reach) in target_values.iter().zip(reached) {
+        if json {
+            answer!("{}", reach_json(*target, &reach));
+        } else {
+            answer!("{}: {}", target, reach);
+        }
+    }
+    Ok(())
+}
+
+// One target of reach --json: when it is first reached, or why it never is
+fn reach_json(target: i64, reach: &Reach) -> Json {
+    let mut fields: Vec<(&str, Json)> = vec![("target", target.into())];
+    match reach {
+        Reach::Start => fields.push(("steps", 0i64.into())),
+        Reach::At { steps, pass, change } => {
+            fields.push(("steps", (*steps).into()));
+            fields.push(("pass", (*pass).into()));
+            fields.push(("change", (*change).into()));
+        }
+        Reach::Never(never) => fields.push(("never", never.to_string().into())),
+    }
+    Json::object(fields)
+}
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use aoc_common::random::Generator;
+use aoc_common::{input, solution, Answer, Extras, Implementation, Result, Solution};
+
+// Solves one part of a day given the whole puzzle input
+pub type Solver = fn(&str) -> Result<Answer>;
+
+// Collects the JSON extras for a part given the whole puzzle input
+pub type ExtrasFn = fn(&str, u32) -> Result<Extras>;
+
+// A solved day: which crate it lives in and the solver for each part
+pub struct Day {
+    pub day: u32,
+    pub crate_name: &'static str,
+    pub part1: Solver,
+    pub part2: Solver,
+    pub extras: ExtrasFn,
+    // Makes up a random input of the day's format, for aoc2018 generate
+    pub generator: Generator,
+    // Named alternative approaches, for aoc2018 bench
+    pub implementations: &'static [Implementation],
+}
+
+impl Day {
+    pub fn part(&self, part: u32) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+
+    // What bench compares for a part: the day's named implementations,
+    // or just its Solution when it has no alternatives
+    pub fn implementations(&self, part: u32) -> Vec<(&'static str, Solver)> {
+        let named: Vec<(&'static str, Solver)> = self
+            .implementations
+            .iter()
+            .filter(|i| i.part == part)
+            .map(|i| (i.name, i.solve))
+            .collect();
+        if !named.is_empty() {
+            return named;
+        }
+        self.part(part).map(|solve| vec![("solution", solve)]).unwrap_or_default()
+    }
+
+    // The checked-in puzzle input
+    pub fn default_input(&self) -> String {
+        repo_path(&format!("{}/input.txt", self.crate_name))
+    }
+
+    // The files given with --input (stdin for "-"), or the day's checked-in input
+    pub fn input_paths(&self, paths: Vec<String>) -> Vec<String> {
+        if paths.is_empty() {
+            vec![self.default_input()]
+        } else {
+            paths
+        }
+    }
+
+    pub fn load_input(&self, paths: Vec<String>) -> Result<input::Sources> {
+        input::Sources::load(&self.input_paths(paths))
+    }
+}
+
+const fn solved<S: Solution>(
+    day: u32,
+    crate_name: &'static str,
+    implementations: &'static [Implementation],
+    generator: Generator,
+) -> Day {
+    Day {
+        day,
+        crate_name,
+        part1: solution::part1::<S>,
+        part2: solution::part2::<S>,
+        extras: solution::extras::<S>,
+        implementations,
+        generator,
+    }
+}
+
+// Day 1 is split over two crates: day1 has the final frequency, day2 the first repeated frequency
+// and the Solution for the whole day. The real day 2 lives in realday2
+pub const DAYS: &[Day] = &[
+    solved::<day2::Calibration>(1, "day1", day2::IMPLEMENTATIONS, day1::generate::generate),
+    solved::<realday2::Inventory>(2, "realday2", realday2::IMPLEMENTATIONS, realday2::generate::generate),
+    solved::<day3::Fabric>(3, "day3", day3::IMPLEMENTATIONS, day3::generate::generate),
+    solved::<day4::ReposeRecord>(4, "day4", &[], day4::generate::generate),
+    solved::<day5::Polymer>(5, "day5", day5::IMPLEMENTATIONS, day5::generate::generate),
+    solved::<day7::Steps>(7, "day7", day7::IMPLEMENTATIONS, day7::generate::generate),
+    solved::<day8::LicenseTree>(8, "day8", &[], day8::generate::generate),
+];
+
+// A checked-in file given relative to the repository root: used as is when run from the root,
+// otherwise found through where this crate was built from
+pub fn repo_path(name: &str) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap_or_else(|| Path::new("."));
+    input::default_path(name, &root.to_string_lossy())
+}
+
+pub fn find(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,56 @@
+use aoc_common::{Json, Result};
+use args::Similarity;
+use realday2::bktree::{BkTree, Match};
+use realday2::edit::Distance;
+use registry;
+
+/*
+    Looks up the catalogue box ids (day 2's input by default) close to each id, by any of realday2's
+    metrics, through a BK-tree over the catalogue
+*/
+pub fn similar(ids: &[String], query: Similarity, metric: Distance, input: Vec<String>, json: bool) -> Result<()> {
+    // The catalogue is day 2's box ids, which only have to be the same length for hamming
+    let solved = registry::find(2).unwrap();
+    let sources = solved.load_input(input)?;
+    let catalogue = match metric {
+        Distance::Hamming => realday2::parse_boxids(&sources.text),
+        _ => realday2::parse_any_boxids(&sources.text),
+    }
+    .map_err(|e| sources.locate(e))?;
+    let tree = BkTree::with_metric(&catalogue, metric.metric());
+    for id in ids {
+        // The catalogue ids are normalized, so the query has to be too
+        let normalized = realday2::unicode::normalize(id);
+        let matches = match query {
+            Similarity::Within(distance) => tree.within(&normalized, distance),
+            Similarity::Nearest(k) => tree.nearest(&normalized, k),
+        };
+        if json {
+            answer!("{}", similar_json(id, &tree, &matches));
+        } else if matches.is_empty() {
+            answer!("{}: none", id);
+        } else {
+            let found: Vec<String> = matches
+                .iter()
+                .map(|m| format!("{} ({})", tree.id(m.index), m.distance))
+                .collect();
+            answer!("{}: {}", id, found.join(", "));
+        }
+    }
+    Ok(())
+}
+
+// One id of similar --json: the catalogue ids found for it, with their line in the catalogue
+fn similar_json(id: &str, tree: &BkTree, matches: &[Match]) -> Json {
+    let found = matches
+        .iter()
+        .map(|m| {
+            Json::object(vec![
+                ("id", tree.id(m.index).into()),
+                ("line", (m.index + 1).into()),
+                ("distance", m.distance.into()),
+            ])
+        })
+        .collect();
+    Json::object(vec![("id", id.into()), ("matches", Json::Array(found))])
+}
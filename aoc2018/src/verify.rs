@@ -0,0 +1,56 @@
+use std::time::{Duration, Instant};
+
+use answers::Expected;
+use aoc_common::{input, Error, Result};
+use registry;
+
+/*
+    Runs every registered day against every recorded answer and reports pass/fail/timing
+
+    Returns the number of answers that did not match (including ones that failed to run)
+*/
+pub fn verify(expected: &[Expected], day: Option<u32>) -> usize {
+    let (mut passed, mut failed) = (0, 0);
+    let start = Instant::now();
+
+    for e in expected.iter().filter(|e| day.is_none() || day == Some(e.day)) {
+        let label = format!("day {} part {} {}", e.day, e.part, e.input);
+        let timer = Instant::now();
+        match check(e) {
+            Ok(ref got) if *got == e.answer => {
+                passed += 1;
+                answer!("PASS  {} ({})", label, millis(timer.elapsed()));
+            }
+            Ok(got) => {
+                failed += 1;
+                answer!("FAIL  {}: expected {}, got {} ({})", label, e.answer, got, millis(timer.elapsed()));
+            }
+            Err(err) => {
+                failed += 1;
+                answer!("ERROR {}: {}", label, err);
+            }
+        }
+    }
+
+    answer!("{} passed, {} failed in {}", passed, failed, millis(start.elapsed()));
+    failed
+}
+
+// Solve the recorded part of the recorded day, returning the answer as written in the answers file
+fn check(e: &Expected) -> Result<String> {
+    let solver = registry::find(e.day)
+        .and_then(|solved| solved.part(e.part))
+        .ok_or_else(|| not_registered(e))?;
+    let path = registry::repo_path(&e.input);
+    let input_string = input::read_to_string(&path)?;
+    let answer = solver(&input_string).map_err(|err| err.in_file(&path))?;
+    Ok(answer.to_string())
+}
+
+fn not_registered(e: &Expected) -> Error {
+    Error::NoAnswer(format!("day {} part {} is not registered", e.day, e.part))
+}
+
+pub fn millis(d: Duration) -> String {
+    format!("{:.2} ms", d.as_secs_f64() * 1000.0)
+}
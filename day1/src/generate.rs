@@ -0,0 +1,26 @@
+use aoc_common::random::{Options, Rng};
+use std::cmp;
+
+const DEFAULT_CHANGES: usize = 1000;
+
+/*
+    Frequency changes like the real input: one +N or -N per line, with N between 1 and 20
+
+    Somewhere in the list a change is immediately undone (+k then -k), so some frequency
+    is always reached twice within the first pass and day 1 part 2 has an answer
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = cmp::max(options.size.unwrap_or(DEFAULT_CHANGES), 2);
+    let undone_at = rng.below(size as u64 - 1) as usize;
+
+    let mut changes: Vec<i64> = Vec::with_capacity(size);
+    while changes.len() < size {
+        let change = rng.range(1, 20) * if rng.chance(0.5) { 1 } else { -1 };
+        changes.push(change);
+        if changes.len() == undone_at + 1 {
+            changes.push(-change);
+        }
+    }
+
+    changes.iter().map(|c| format!("{:+}\n", c)).collect()
+}
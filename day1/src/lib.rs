@@ -0,0 +1,135 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate num_bigint;
+extern crate num_integer;
+extern crate num_traits;
+
+use aoc_common::parse::Scanner;
+use aoc_common::{Answer, Error, ParseError, Result};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedSub, Signed, ToPrimitive};
+use std::fmt::Display;
+use std::hash::Hash;
+
+pub mod generate;
+
+// The frequency changes, remembering where each was written so that errors can point at it
+pub struct Changes {
+    pub values: Vec<i64>,
+    // (line, byte offset within the line) of each change
+    positions: Vec<(usize, usize)>,
+    text: String,
+}
+
+impl Changes {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // An error pointing at the change with the given index
+    pub fn error_at(&self, index: usize, reason: &str) -> Error {
+        let (line, offset) = self.positions[index];
+        let text = self.text.lines().nth(line - 1).unwrap_or("");
+        let column = text[..offset].chars().count() + 1;
+        ParseError::new(line, column, text, reason).into()
+    }
+}
+
+/*
+    Frequency changes such as +3, -14 or 7 (the + is optional), separated by newlines, whitespace
+    and/or commas, so that both the real input (one per line) and the puzzle's examples
+    ("+1, -2, +3, +1") parse. A comma may end a line, for lists spread over several lines.
+    Blank lines are skipped, and # starts a comment that runs to the end of the line
+*/
+pub fn parse_changes(input: &str) -> Result<Changes> {
+    let mut changes = Changes {
+        values: Vec::new(),
+        positions: Vec::new(),
+        text: input.to_string(),
+    };
+    for (i, line) in input.lines().enumerate() {
+        let mut scanner = Scanner::new(line, i + 1);
+        loop {
+            scanner.skip_whitespace();
+            if at_line_end(&scanner) {
+                break;
+            }
+            let offset = scanner.offset();
+            changes.values.push(scanner.int()?);
+            changes.positions.push((i + 1, offset));
+
+            if !at_line_end(&scanner) && !scanner.rest().starts_with(|c: char| c == ',' || c.is_whitespace()) {
+                return Err(scanner.error("expected a comma or whitespace after the change").into());
+            }
+            scanner.skip_whitespace();
+            scanner.optional(",");
+        }
+    }
+    Ok(changes)
+}
+
+// Nothing but a comment left on the line
+fn at_line_end(scanner: &Scanner) -> bool {
+    scanner.is_empty() || scanner.rest().starts_with('#')
+}
+
+/*
+    What a running frequency is kept in
+
+    i64 is the default and checks every addition, so a frequency that does not fit is an error
+    pointing at the change that overflowed instead of silently wrapping around in release builds.
+    BigInt never overflows, for inputs that really do go that far
+*/
+pub trait Frequency:
+    Integer + Signed + CheckedAdd + CheckedSub + ToPrimitive + Clone + Hash + Display + From<i64>
+{
+    // None if the new frequency does not fit
+    fn add_change(&self, change: i64) -> Option<Self>;
+
+    fn into_answer(self) -> Answer;
+}
+
+impl Frequency for i64 {
+    fn add_change(&self, change: i64) -> Option<i64> {
+        i64::checked_add(*self, change)
+    }
+
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+}
+
+impl Frequency for BigInt {
+    fn add_change(&self, change: i64) -> Option<BigInt> {
+        Some(self + change)
+    }
+
+    // Still a number when it fits, otherwise written out in full
+    fn into_answer(self) -> Answer {
+        match self.to_i64() {
+            Some(n) => n.into(),
+            None => self.to_string().into(),
+        }
+    }
+}
+
+pub const OVERFLOW: &str = "the frequency no longer fits in 64 bits after this change (the bigint implementation has no limit)";
+
+// Starting from 0, apply every frequency change once
+pub fn final_frequency<F: Frequency>(changes: &Changes) -> Result<F> {
+    let mut count = F::zero();
+    // Iteratng through each frequency change
+    for (i, val) in changes.values.iter().enumerate() {
+        count = count
+            .add_change(*val)
+            .ok_or_else(|| changes.error_at(i, OVERFLOW))?;
+
+        trace!("val: {}", val);
+    }
+    Ok(count)
+}
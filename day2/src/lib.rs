@@ -0,0 +1,176 @@
+extern crate aoc_common;
+extern crate day1;
+extern crate num_bigint;
+
+use aoc_common::solution;
+use aoc_common::{Answer, Error, Extras, Implementation, Json, Result, Solution};
+use day1::{Changes, Frequency};
+use num_bigint::BigInt;
+
+pub mod reach;
+pub mod repeats;
+
+// The first frequency reached twice, or None if no frequency is ever reached twice (see repeats)
+pub fn first_repeated_frequency<F: Frequency>(changes: &Changes) -> Result<Option<F>> {
+    Ok(repeats::first_repeat(changes)?.map(|repeat| repeat.frequency))
+}
+
+// Day 1 as a whole: day1 has the parsing and the final frequency, this crate the first repeat
+pub struct Calibration;
+
+// Both parts with a 64-bit frequency that errors on overflow (the Solution), or a BigInt one
+pub const IMPLEMENTATIONS: &[Implementation] = &[
+    Implementation { name: "checked", part: 1, solve: solution::part1::<Calibration> },
+    Implementation { name: "bigint", part: 1, solve: part1_bigint },
+    Implementation { name: "checked", part: 2, solve: solution::part2::<Calibration> },
+    Implementation { name: "bigint", part: 2, solve: part2_bigint },
+];
+
+fn part1_bigint(input: &str) -> Result<Answer> {
+    Ok(day1::final_frequency::<BigInt>(&day1::parse_changes(input)?)?.into_answer())
+}
+
+fn part2_bigint(input: &str) -> Result<Answer> {
+    repeat_answer(first_repeated_frequency::<BigInt>(&day1::parse_changes(input)?)?)
+}
+
+fn repeat_answer<F: Frequency>(repeat: Option<F>) -> Result<Answer> {
+    repeat
+        .map(F::into_answer)
+        .ok_or_else(|| Error::NoAnswer("no frequency is ever reached twice".to_string()))
+}
+
+impl Solution for Calibration {
+    type Input = Changes;
+
+    fn parse(input: &str) -> Result<Changes> {
+        day1::parse_changes(input)
+    }
+
+    fn part1(changes: &Changes) -> Result<Answer> {
+        Ok(day1::final_frequency::<i64>(changes)?.into())
+    }
+
+    fn part2(changes: &Changes) -> Result<Answer> {
+        repeat_answer(first_repeated_frequency::<i64>(changes)?)
+    }
+
+    // Part 2 also reports when the repeat happens and how far the frequency drifts on each pass
+    fn extras(changes: &Changes, part: u32) -> Result<Extras> {
+        if part == 1 {
+            return Ok(vec![("changes", changes.len().into())]);
+        }
+        let mut extras: Extras = vec![("drift", day1::final_frequency::<i64>(changes)?.into())];
+        match repeats::first_repeat::<i64>(changes)? {
+            Some(repeat) => extras.extend(vec![
+                ("first_repeat", repeat.frequency.into()),
+                ("pass", repeat.pass.into()),
+                ("change", repeat.change.into()),
+                ("first_seen_pass", repeat.first_seen.map_or(Json::Null, |(pass, _)| pass.into())),
+                ("first_seen_change", repeat.first_seen.map_or(Json::Null, |(_, change)| change.into())),
+                ("steps", repeat.steps.into()),
+            ]),
+            None => extras.push(("first_repeat", Json::Null)),
+        }
+        Ok(extras)
+    }
+}
+
+// repeats and reach work from one pass, so check them against just applying the changes over and over
+#[cfg(test)]
+mod tests {
+    use super::reach::{self, Reach};
+    use super::repeats;
+    use day1;
+    use std::collections::hash_map::{Entry, HashMap};
+
+    // Long enough for every repeat and reach of the inputs below to show up many times over
+    const STEPS: u128 = 2000;
+
+    const INPUTS: &[&str] = &[
+        // Never repeats
+        "+1, +1",
+        // Positive drift (the puzzle's examples)
+        "+1, -2, +3, +1",
+        "+3, +3, +4, -2, -4",
+        "-6, +3, +8, +5, -6",
+        "+7, +7, -2, -7, -4",
+        // Negative drift
+        "+3, -5, +1",
+        "-6, +3, +8, -7",
+        "-1, -1",
+        // Zero drift
+        "+1, -1",
+        "+3, +3, +4, -2, -4, -4",
+        "0",
+    ];
+
+    // (step each frequency was first reached at, (frequency, step it was reached again at) in order)
+    fn simulate(changes: &[i64]) -> (HashMap<i64, u128>, Vec<(i64, u128)>) {
+        let mut first: HashMap<i64, u128> = HashMap::new();
+        let mut again: Vec<(i64, u128)> = Vec::new();
+        let mut frequency = 0;
+        first.insert(0, 0);
+        for step in 1..=STEPS {
+            frequency += changes[(step as usize - 1) % changes.len()];
+            match first.entry(frequency) {
+                Entry::Occupied(_) => {
+                    if !again.iter().any(|(f, _)| *f == frequency) {
+                        again.push((frequency, step));
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(step);
+                }
+            }
+        }
+        (first, again)
+    }
+
+    #[test]
+    fn repeats_match_simulation() {
+        for input in INPUTS {
+            let changes = day1::parse_changes(input).unwrap();
+            let (first, again) = simulate(&changes.values);
+            // One more than the simulation saw, which must come after it stopped if it exists
+            let found = repeats::repeats::<i64>(&changes, again.len() + 1).unwrap();
+            for (repeat, (frequency, steps)) in found.iter().zip(&again) {
+                assert_eq!((repeat.frequency, repeat.steps), (*frequency, *steps), "{}", input);
+                let first_steps = repeat
+                    .first_seen
+                    .map_or(0, |(pass, change)| (pass - 1) * changes.len() as u128 + change as u128 + 1);
+                assert_eq!(first_steps, first[frequency], "{}", input);
+            }
+            assert!(found.len() >= again.len(), "{}", input);
+            if let Some(extra) = found.get(again.len()) {
+                assert!(extra.steps > STEPS, "{}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn never_repeats() {
+        let changes = day1::parse_changes("+1, +1").unwrap();
+        assert_eq!(repeats::first_repeat::<i64>(&changes).unwrap(), None);
+    }
+
+    #[test]
+    fn first_reached_matches_simulation() {
+        for input in INPUTS {
+            let changes = day1::parse_changes(input).unwrap();
+            let (first, _) = simulate(&changes.values);
+            let targets: Vec<i64> = (-40..=40).collect();
+            let reached = reach::first_reached(&changes, &targets).unwrap();
+            for (target, reach) in targets.iter().zip(reached) {
+                match (first.get(target), reach) {
+                    (Some(0), Reach::Start) => {}
+                    (Some(steps), Reach::At { steps: at, .. }) => assert_eq!(at, *steps, "{}: {}", input, target),
+                    // Only reached after the simulation stopped, if at all
+                    (None, Reach::Never(_)) => {}
+                    (None, Reach::At { steps, .. }) => assert!(steps > STEPS, "{}: {}", input, target),
+                    (expected, reach) => panic!("{}: {} expected {:?}, got {:?}", input, target, expected, reach),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_common::Result;
+use day1::{Changes, Frequency, OVERFLOW};
+use repeats::orbits;
+use std::fmt;
+
+/*
+    When is a frequency first reached, if ever? Answered from one pass like repeats:
+    the frequency after j + m*n changes is p[j] + m*D, so a target F is reached from orbit j
+    exactly when F - p[j] is a non-negative multiple of the drift D (in the direction of the drift)
+
+    - With no drift the first pass's frequencies come round forever, and nothing else is ever reached
+    - Otherwise only orbits with p[j] congruent to F mod |D| can reach it, and the one that gets there
+      first is the nearest one behind F (fewest passes), and of those the earliest in the pass
+
+    Sorting the p by residue and then frequency once answers each target with a binary search:
+    O((n + targets) log n)
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reach {
+    // It is the starting frequency
+    Start,
+    // First reached after steps changes, by the change (counting from 0) on the pass (counting from 1)
+    At { steps: u128, pass: u128, change: usize },
+    Never(Never),
+}
+
+// Why a frequency is never reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Never {
+    // With no drift only the first pass's frequencies are ever reached, and it is not one of them
+    NotInFirstPass,
+    // No frequency of the first pass is congruent to it modulo the drift
+    NoMatchingResidue,
+    // Every frequency congruent to it starts beyond it, and the drift only carries them further away
+    AgainstDrift,
+}
+
+impl fmt::Display for Reach {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reach::Start => write!(f, "the starting frequency"),
+            Reach::At { steps, pass, change } => {
+                write!(f, "reached by change {} on pass {} ({} steps)", change, pass, steps)
+            }
+            Reach::Never(never) => write!(f, "never reached: {}", never),
+        }
+    }
+}
+
+impl fmt::Display for Never {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Never::NotInFirstPass => write!(f, "there is no drift and it is not reached in the first pass"),
+            Never::NoMatchingResidue => write!(f, "no frequency in the first pass is congruent to it modulo the drift"),
+            Never::AgainstDrift => write!(f, "every frequency that could reach it has drifted past it already"),
+        }
+    }
+}
+
+impl Reach {
+    // Reach after the given number of changes, out of n a pass
+    fn after(steps: u128, n: usize) -> Reach {
+        if steps == 0 {
+            return Reach::Start;
+        }
+        Reach::At {
+            steps,
+            pass: (steps - 1) / n as u128 + 1,
+            change: ((steps - 1) % n as u128) as usize,
+        }
+    }
+}
+
+// When each of the targets is first reached
+pub fn first_reached<F: Frequency>(changes: &Changes, targets: &[F]) -> Result<Vec<Reach>> {
+    let n = changes.len();
+    let (prefix_sums, drift, modulus) = orbits::<F>(changes)?;
+    if n == 0 {
+        // Nothing ever changes
+        return Ok(targets
+            .iter()
+            .map(|target| if target.is_zero() { Reach::Start } else { Reach::Never(Never::NotInFirstPass) })
+            .collect());
+    }
+
+    // Without a drift everything is in one class, sorted by frequency
+    let residue = |p: &F| if drift.is_zero() { F::zero() } else { p.mod_floor(&modulus) };
+
+    // (residue, frequency, step): equal frequencies end up sorted by step
+    let mut sorted: Vec<(F, F, usize)> = prefix_sums
+        .into_iter()
+        .enumerate()
+        .map(|(step, p)| (residue(&p), p, step))
+        .collect();
+    sorted.sort();
+
+    let mut reached: Vec<Reach> = Vec::with_capacity(targets.len());
+    for target in targets {
+        let r = residue(target);
+        let class_start = sorted.partition_point(|(res, _, _)| *res < r);
+        let class_end = sorted.partition_point(|(res, _, _)| *res <= r);
+        let class = &sorted[class_start..class_end];
+        if class.is_empty() {
+            reached.push(Reach::Never(if drift.is_zero() { Never::NotInFirstPass } else { Never::NoMatchingResidue }));
+            continue;
+        }
+
+        // The nearest frequency behind the target (or on it), and the earliest orbit with that frequency
+        let nearest = if drift.is_zero() {
+            let at = class.partition_point(|(_, p, _)| p < target);
+            class.get(at).filter(|(_, p, _)| p == target)
+        } else if drift.is_positive() {
+            let above = class.partition_point(|(_, p, _)| p <= target);
+            above.checked_sub(1).map(|last| &class[class.partition_point(|(_, p, _)| *p < class[last].1)])
+        } else {
+            let at = class.partition_point(|(_, p, _)| p < target);
+            class.get(at)
+        };
+        let (_, start, step) = match nearest {
+            Some(nearest) => nearest,
+            None => {
+                reached.push(Reach::Never(if drift.is_zero() { Never::NotInFirstPass } else { Never::AgainstDrift }));
+                continue;
+            }
+        };
+
+        let passes = if drift.is_zero() {
+            0
+        } else {
+            let distance = if drift.is_positive() { target.checked_sub(start) } else { start.checked_sub(target) };
+            distance
+                .map(|d| d / modulus.clone())
+                .and_then(|passes| passes.to_u128())
+                .ok_or_else(|| changes.error_at(*step, OVERFLOW))?
+        };
+        reached.push(Reach::after(*step as u128 + passes * n as u128, n));
+    }
+    Ok(reached)
+}
@@ -0,0 +1,228 @@
+use aoc_common::Result;
+use day1::{Changes, Frequency, OVERFLOW};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+/*
+    Frequencies reached twice when the changes are applied over and over, starting from 0
+    (which counts as reached), worked out from one pass instead of simulating
+
+    Simulating never stops when nothing repeats (+1, +1), and can take a very long time when the drift
+    of one pass (the sum of all changes) is small next to the spread of the frequencies. Instead:
+
+    - p[j] is the frequency after the first j changes (p[0] = 0), and D the drift of one pass,
+      so the frequency after j + m*n changes is p[j] + m*D: "orbit j"
+    - Two orbits can only meet if p[i] and p[j] differ by a multiple of D, so group the p by residue
+      mod |D| and sort each group in the direction of the drift. Orbits with the same p form a bunch
+    - Orbit i reaches the next bunch up k = (p[next] - p[i]) / D passes later. Every frequency from
+      a bunch's own p up to (not including) the next bunch's is reached first by that bunch,
+      and again either by its second orbit (when the bunch has two) or, k passes later,
+      by the first orbit of the bunch below
+    - So each bunch gives an arithmetic run of repeats, one a pass, and merging the runs in order
+      of when they happen gives every repeat in order. Without a second orbit or a bunch below,
+      a bunch's frequencies are never reached twice. With no drift, every frequency of the first
+      pass comes round again on the second
+
+    O(n log n) to sort, plus O(log n) for each repeat wanted, however many passes a simulation
+    would need. Every frequency involved is a prefix sum plus some drifts, so only those need to fit in F
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repeat<F> {
+    pub frequency: F,
+    // How many changes had been applied when the frequency was reached again: the steps a simulation takes
+    pub steps: u128,
+    // The pass through the list (counting from 1) and the change (counting from 0) that reached it again
+    pub pass: u128,
+    pub change: usize,
+    // The pass and change that first reached it, None if it is the starting frequency
+    pub first_seen: Option<(u128, usize)>,
+}
+
+impl<F: fmt::Display> fmt::Display for Repeat<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} first reached ", self.frequency)?;
+        match self.first_seen {
+            Some((pass, change)) => write!(f, "by change {} on pass {}", change, pass)?,
+            None => write!(f, "at the start")?,
+        }
+        write!(
+            f,
+            ", again by change {} on pass {} ({} steps)",
+            self.change, self.pass, self.steps
+        )
+    }
+}
+
+// A run of repeats, one a pass: the m-th is reached again after from + (passes + m) * n changes
+// and first after first + m * n changes
+struct Run<F> {
+    passes: u128,
+    from: usize,
+    first: usize,
+    // How many repeats are in the run, None for no end
+    count: Option<u128>,
+    frequency: F,
+}
+
+// Where the orbits start and how far they move each pass: the frequency after each number of changes in
+// the first pass (p), the drift D, and |D| to take residues modulo (also used by reach)
+pub fn orbits<F: Frequency>(changes: &Changes) -> Result<(Vec<F>, F, F)> {
+    let n = changes.len();
+    let mut prefix_sums: Vec<F> = Vec::with_capacity(n);
+    let mut frequency = F::zero();
+    for (i, change) in changes.values.iter().enumerate() {
+        prefix_sums.push(frequency.clone());
+        frequency = frequency
+            .add_change(*change)
+            .ok_or_else(|| changes.error_at(i, OVERFLOW))?;
+    }
+    let drift = frequency;
+    let modulus = if drift.is_negative() {
+        F::zero()
+            .checked_sub(&drift)
+            .ok_or_else(|| changes.error_at(n - 1, OVERFLOW))?
+    } else {
+        drift.clone()
+    };
+    Ok((prefix_sums, drift, modulus))
+}
+
+// The first repeat, if any frequency is ever reached twice
+pub fn first_repeat<F: Frequency>(changes: &Changes) -> Result<Option<Repeat<F>>> {
+    Ok(repeats(changes, 1)?.pop())
+}
+
+// Up to limit frequencies reached twice, in the order they are reached for the second time
+pub fn repeats<F: Frequency>(changes: &Changes, limit: usize) -> Result<Vec<Repeat<F>>> {
+    let n = changes.len();
+    if n == 0 || limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let (prefix_sums, drift, modulus) = orbits::<F>(changes)?;
+    let runs = if drift.is_zero() {
+        runs_without_drift(prefix_sums)
+    } else {
+        runs_with_drift(changes, prefix_sums, &drift, &modulus)?
+    };
+
+    // Merge the runs by (passes, from), which orders the repeats by from + passes * n since from < n
+    let mut runs = runs;
+    let mut heap: BinaryHeap<Reverse<(u128, usize, usize, u128)>> = runs
+        .iter()
+        .enumerate()
+        .filter(|(_, run)| run.count != Some(0))
+        .map(|(i, run)| Reverse((run.passes, run.from, i, 0)))
+        .collect();
+
+    let mut found: Vec<Repeat<F>> = Vec::new();
+    while let Some(Reverse((passes, from, i, m))) = heap.pop() {
+        let run = &mut runs[i];
+        let steps = from as u128 + passes * n as u128;
+        let first_steps = run.first as u128 + m * n as u128;
+        found.push(Repeat {
+            frequency: run.frequency.clone(),
+            steps,
+            pass: (steps - 1) / n as u128 + 1,
+            change: ((steps - 1) % n as u128) as usize,
+            first_seen: if first_steps == 0 {
+                None
+            } else {
+                Some(((first_steps - 1) / n as u128 + 1, ((first_steps - 1) % n as u128) as usize))
+            },
+        });
+        if found.len() == limit {
+            break;
+        }
+
+        if run.count.is_none_or(|count| m + 1 < count) {
+            // Only the last of many repeats can overflow, so stop there rather than fail
+            match run.frequency.checked_add(&drift) {
+                Some(next) => run.frequency = next,
+                None => continue,
+            }
+            heap.push(Reverse((passes + 1, from, i, m + 1)));
+        }
+    }
+    Ok(found)
+}
+
+// With no drift every pass reaches the same frequencies, so each is reached again either later in the
+// first pass or at the same point of the second
+fn runs_without_drift<F: Frequency>(prefix_sums: Vec<F>) -> Vec<Run<F>> {
+    let mut sorted: Vec<(F, usize)> = prefix_sums.into_iter().enumerate().map(|(step, p)| (p, step)).collect();
+    sorted.sort();
+
+    let mut runs: Vec<Run<F>> = Vec::new();
+    for bunch in bunches(&sorted, |(p, _)| p) {
+        let (ref frequency, first) = bunch[0];
+        let (passes, from) = match bunch.get(1) {
+            Some((_, second)) => (0, *second),
+            None => (1, first),
+        };
+        runs.push(Run { passes, from, first, count: Some(1), frequency: frequency.clone() });
+    }
+    runs
+}
+
+fn runs_with_drift<F: Frequency>(changes: &Changes, prefix_sums: Vec<F>, drift: &F, modulus: &F) -> Result<Vec<Run<F>>> {
+    // (residue, frequency, step): sorts each residue class by frequency, and each bunch by step
+    let mut sorted: Vec<(F, F, usize)> = prefix_sums
+        .into_iter()
+        .enumerate()
+        .map(|(step, p)| (p.mod_floor(modulus), p, step))
+        .collect();
+    sorted.sort();
+
+    let mut runs: Vec<Run<F>> = Vec::new();
+    for class in bunches(&sorted, |(residue, _, _)| residue) {
+        let mut class: Vec<&[(F, F, usize)]> = bunches(class, |(_, p, _)| p);
+        // Walk the bunches in the direction the drift carries the frequencies
+        if drift.is_negative() {
+            class.reverse();
+        }
+
+        // Passes from each bunch to the next
+        let mut gaps: Vec<u128> = Vec::with_capacity(class.len());
+        for pair in class.windows(2) {
+            let (low, high) = if drift.is_positive() {
+                (&pair[0][0].1, &pair[1][0].1)
+            } else {
+                (&pair[1][0].1, &pair[0][0].1)
+            };
+            let gap = high
+                .checked_sub(low)
+                .ok_or_else(|| changes.error_at(pair[1][0].2, OVERFLOW))?
+                / modulus.clone();
+            gaps.push(gap.to_u128().ok_or_else(|| changes.error_at(pair[1][0].2, OVERFLOW))?);
+        }
+
+        for (b, bunch) in class.iter().enumerate() {
+            let (_, ref frequency, first) = bunch[0];
+            let (passes, from) = match (bunch.get(1), b) {
+                // The bunch's own second orbit reaches each of its frequencies again
+                (Some((_, _, second)), _) => (0, *second),
+                // Otherwise the first orbit of the bunch below, once it catches up
+                (None, b) if b > 0 => (gaps[b - 1], class[b - 1][0].2),
+                _ => continue,
+            };
+            // The last bunch's run has no end
+            runs.push(Run { passes, from, first, count: gaps.get(b).cloned(), frequency: frequency.clone() });
+        }
+    }
+    Ok(runs)
+}
+
+// Splits a sorted slice into the runs of consecutive items with the same key
+fn bunches<T, K: PartialEq, G: Fn(&T) -> &K>(items: &[T], key: G) -> Vec<&[T]> {
+    let mut bunches: Vec<&[T]> = Vec::new();
+    let mut start = 0;
+    for i in 1..=items.len() {
+        if i == items.len() || key(&items[i]) != key(&items[start]) {
+            bunches.push(&items[start..i]);
+            start = i;
+        }
+    }
+    bunches
+}
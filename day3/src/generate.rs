@@ -0,0 +1,48 @@
+use aoc_common::random::{Options, Rng};
+use std::cmp;
+
+const DEFAULT_CLAIMS: usize = 1300;
+const FABRIC_SIZE: i64 = 1000;
+// Every claim but one lies left of this column; the intact claim lies right of INTACT_X
+const CROWDED_WIDTH: i64 = 960;
+const INTACT_X: i64 = 970;
+
+/*
+    Claims like "#123 @ 3,2: 5x4" on a 1000x1000 fabric, each between 5 and 29 inches on a side
+
+    Every claim after the first is placed overlapping an earlier one (so the first overlaps the second),
+    except for one claim placed alone in a strip on the right: exactly one claim is intact,
+    which is the part 2 answer. Claim ids run from 1 in the order of the lines
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = cmp::max(options.size.unwrap_or(DEFAULT_CLAIMS), 3);
+
+    // (x, y, width, height)
+    let mut claims: Vec<(i64, i64, i64, i64)> = Vec::with_capacity(size);
+    while claims.len() < size - 1 {
+        let (w, h) = (rng.range(5, 29), rng.range(5, 29));
+        let claim = if claims.is_empty() {
+            (rng.range(0, CROWDED_WIDTH - w), rng.range(0, FABRIC_SIZE - h), w, h)
+        } else {
+            let &(px, py, pw, ph) = rng.pick(&claims);
+            (
+                rng.range(cmp::max(0, px - w + 1), cmp::min(CROWDED_WIDTH - w, px + pw - 1)),
+                rng.range(cmp::max(0, py - h + 1), cmp::min(FABRIC_SIZE - h, py + ph - 1)),
+                w,
+                h,
+            )
+        };
+        claims.push(claim);
+    }
+
+    let (w, h) = (rng.range(5, 29), rng.range(5, 29));
+    let intact = (rng.range(INTACT_X, FABRIC_SIZE - w), rng.range(0, FABRIC_SIZE - h), w, h);
+    let intact_at = rng.below(size as u64) as usize;
+    claims.insert(intact_at, intact);
+
+    claims
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y, w, h))| format!("#{} @ {},{}: {}x{}\n", i + 1, x, y, w, h))
+        .collect()
+}
@@ -0,0 +1,404 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate binary_heap_plus;
+
+use aoc_common::parse::Scanner;
+use aoc_common::solution;
+use aoc_common::{Answer, Error, Implementation, ParseError, Result, Solution};
+use std::collections::hash_map::{Entry, HashMap};
+use std::vec::Vec;
+use binary_heap_plus::*;
+
+pub mod generate;
+pub mod pairwise;
+
+/*
+    Problem: Find the total square area of overalapping rectangles
+
+    - How do we check that two rectangles overlap, and if so, return their
+      overlapping area?
+      --> Look at their 1D intersections (solve as a 1D line overlap problem x 2)
+    
+    - Input format: #123 @ 3,2: 5x4 ==> Claim 123, 3 units from left, 2 units from top, 5 wide, 4 tall
+
+    - Conventions for this program: x axis ---->, y axis vertical
+
+*/
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32
+}
+
+// Our default ordering is going to be via our bottom right coordinate
+// This is so our priority queue can be sorted by the next rectangle to remove
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rect {
+    pub botright: Point,
+    pub topleft: Point,
+    pub claim: i32,
+}
+
+impl std::fmt::Debug for Rect {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "<({},{})-({},{})>", self.topleft.x, self.topleft.y, self.botright.x, self.botright.y/*, self.botright.x - self.topleft.x, self.botright.y - self.topleft.y*/)
+    }
+}
+
+/*
+
+    Algorithm for day3 (Algorithm 3.1: Line Sweep)
+    - For x coordinate in (0 ... highest x coordinate of set of fabrics)
+        - Find the set of rectangles that are within this x-coordinate (Opt 3.1)
+        - The problem can now be converted into a 1D line-overlap problem on the y-axis
+            - Find the line segments in the y-axis that overlap for 2 or more times (Algorithm 3.2)
+            - Since we are adjusting the line sweep by 1 unit each time, add (total overlap distance * 1) to the total area that contributes to the overlap 
+    
+    Algorithm 3.2: 1D line segment overlap
+        - We now have a list of rectangles that are in this x-coordinate
+        - We must compute the 1D overlapping segment distance of this cross-section of the rectangles
+            - Imagine the rectangular cross-sections at this x-coord (easier to draw), e.g.
+                --- 
+                 ----
+                 --
+                ------
+                        ----   
+                           ---
+            - We basically do a 1D line-sweep across this region to find the total intersection area
+            - Opt 3.2 applies
+            
+
+    Optimizations
+        - Opt 3.1: The set of rectangles that are within the sweep line for that x-coordinate can be computed cheaply by considering that
+            - We add a rectangle into the set iff its topleft x-coordinate matches the sweep x coord
+            - We remove a rectangle from the set iff its botright x-coordinate matches the sweep x coord
+            ==> We need a list of rectangles sorted by starting (topleft) x-coordinate
+            ==> Our current set of rectangles can be modeled as a priority queue sorted by ending (botright) x-coordinate: we keep peeking at this list on every iteration to see if we need to remove any rectangles
+        - Opt 3.2: If we did not remove or add any rectangles to the set of overlapping rectangles in this iteration: we do not need to recompute the result of Algorithm 3.2 since the overlapping distance is still the same
+
+*/
+
+/*
+    Claims are "#id @ x,y: wxh", one per line, but written by hand as often as not, so:
+    - spaces and tabs are allowed (not required) around every part, and the # can be left out
+    - CRLF line endings and blank lines are fine, and the x can be an X
+    - sizes must be at least 1 by 1, and no two claims can have the same id
+
+    Every bad line is reported, not just the first
+*/
+pub fn parse_rects(input: &str) -> Result<Vec<Rect>> {
+    let mut rects: Vec<Rect> = Vec::new();
+    let mut errors: Vec<ParseError> = Vec::new();
+    // Claim id -> the line it was first given on (as text: line numbers only mean something once the
+    // error has been traced back to its file)
+    let mut claims: HashMap<i32, &str> = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_claim(line, i + 1) {
+            Ok((rect, id_column)) => match claims.entry(rect.claim) {
+                Entry::Occupied(first) => {
+                    let reason = format!("claim #{} was already given (as \"{}\")", rect.claim, first.get().trim());
+                    errors.push(ParseError::new(i + 1, id_column, line, &reason));
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(line);
+                    rects.push(rect);
+                }
+            },
+            Err(Error::Parse(e)) => errors.push(e),
+            Err(e) => return Err(e),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    // Sort the rectangles (custom) by their topleft coordinate - we can iterate through this to
+    // find our rectangles for the sweep x coordinate
+    rects.sort_by_key(|r1| r1.topleft.x);
+    Ok(rects)
+}
+
+// Part 1: line sweep over x (Algorithm 3.1), with a 1D line sweep over y at each step (Algorithm 3.2)
+// rects must be sorted by topleft x coordinate, as parse_rects returns them
+pub fn overlap_area(rects: &[Rect]) -> u32 {
+    if rects.is_empty() { return 0; }
+
+    // The highest_x_coord will be the further we will do our line scan
+    let highest_x_coord = rects.iter().map(|r| r.botright.x).max().unwrap();
+
+    trace!("Rects sorted (Vector - Min Topleft): \n\n {:?} \n\n", rects);
+
+    // A minimum heap (sorted by bottom right coordinate (and then by x coordinate first))
+    let mut priority_rects: BinaryHeap<&Rect, MinComparator> = BinaryHeap::new_min();
+
+
+    // Small optimization, don't start from x = 0, but start from the lowest available x coord of rects
+    let lowest_x_coord = rects[0].topleft.x;
+    let mut rect_idx = 0; // index into the rects vector to get the next vector to 
+    let mut overlap_area = 0;
+
+    // Iterate through all x coordinates across fabric
+    for sweep_x in lowest_x_coord..(highest_x_coord+1) {
+        trace!("Sweep Line X Coord: {}", sweep_x);
+        pop_processed_rects(&mut priority_rects, sweep_x);
+        push_rects_on_sweep_line(&mut priority_rects, sweep_x, rects, &mut rect_idx);
+        let overlap_distance = calculate_overlap_distance(priority_rects.clone());
+        overlap_area += overlap_distance;
+        // We now have a binary heap that contains all of the rectangles in this sweep line
+        // for this iteration
+        // Run Algorithm 3.2 to get the 1D line overlap
+        
+        // Remember that it's fine if this doesn't print out a total order: a heap is not completely ordered
+        trace!("Priority Q status: {:?}\n", priority_rects);
+    }
+    overlap_area
+}
+
+// Part2: The claims that don't overlap with anyone else
+// The lazy solution is an n^2 all-pairs intersection check
+pub fn non_overlapping_claims(rects: &[Rect]) -> Vec<i32> {
+    let mut claims: Vec<i32> = Vec::new();
+    for rect in rects {
+        // Check for intersection with everyone else, if it only intersects with itself, this is our guy
+        if rects.iter().map(|r| rectangular_intersection(rect, r)).filter(|x| x.is_some()).count() == 1 {
+            info!("Rect Claim {} has no intersections with any other rect", rect.claim);
+            claims.push(rect.claim);
+        }
+    }
+    claims
+}
+
+pub struct Fabric;
+
+// Part 1 can be solved with the line sweep or by unioning all pairwise intersections
+pub const IMPLEMENTATIONS: &[Implementation] = &[
+    Implementation { name: "sweep", part: 1, solve: solution::part1::<Fabric> },
+    Implementation { name: "pairwise", part: 1, solve: pairwise::part1 },
+    Implementation { name: "all-pairs", part: 2, solve: solution::part2::<Fabric> },
+];
+
+impl Solution for Fabric {
+    type Input = Vec<Rect>;
+
+    fn parse(input: &str) -> Result<Vec<Rect>> {
+        parse_rects(input)
+    }
+
+    fn part1(rects: &Vec<Rect>) -> Result<Answer> {
+        Ok(overlap_area(rects).into())
+    }
+
+    fn part2(rects: &Vec<Rect>) -> Result<Answer> {
+        non_overlapping_claims(rects)
+            .first()
+            .map(|claim| Answer::from(*claim))
+            .ok_or_else(|| Error::NoAnswer("every claim overlaps with another claim".to_string()))
+    }
+}
+
+// Remove all rectangles from prioritiy queue that are exceeding the sweep line's x value
+fn pop_processed_rects(priority_rects: &mut BinaryHeap<&Rect, MinComparator>, sweep_x: i32) {
+    // TODO
+    loop {
+        let mut pop_priority_rects = false;
+
+        // Peek at priority queue to see if we need to dequeue a rect
+        if let Some(rect) = priority_rects.peek() {
+            if rect.botright.x == sweep_x {
+                // Rectangle is going out of the sweep line - remove it
+                pop_priority_rects = true;
+            } else {
+                // Closest rect is not in the sweep line, carry on trying to add new rects
+                break;
+            }
+        } 
+
+        // Marked for popping (we did this to avoid mutable/immutable borrow collision above)
+        if pop_priority_rects {
+            priority_rects.pop();
+            continue;
+        }
+        break
+        // If None, break
+    }
+}
+
+// Add all of the rectangles in this x-coord to the priority queue
+fn push_rects_on_sweep_line<'a>(priority_rects: &mut BinaryHeap<&'a Rect, MinComparator>, sweep_x: i32, rects: &'a [Rect], rect_idx: &mut usize) {
+        loop {
+            if *rect_idx == rects.len() { break; }
+            if rects[*rect_idx].topleft.x == sweep_x {
+                priority_rects.push(&rects[*rect_idx]);
+                *rect_idx += 1;
+            } else {
+                break;
+            }
+        }
+}
+
+// Given the current list of rectangles in the sweep line, calculate the line overlap
+fn calculate_overlap_distance(priority_rects: BinaryHeap<&Rect, MinComparator>) -> u32 {
+    // No rects == no overlap
+    if priority_rects.is_empty() { return 0; }
+
+    // Sort vectors by their starting y-coordinate
+    let mut rects: Vec<&Rect> = priority_rects.into_vec();
+    rects.sort_by_key(|r1| r1.topleft.y);
+
+    // We also sort them by their ending y-coordinate to decrement as ncessary
+    let mut rects_end: Vec<&Rect> = rects.clone();
+    rects_end.sort_by_key(|r1| r1.botright.y);
+    
+    let (mut rects_idx, mut rects_end_idx) = (0, 0);
+    let mut current_num_overlap = 0; 
+    let mut total_intersection_distance = 0;
+
+    let lowest_y = rects.first().unwrap().topleft.y;
+    let highest_y = rects_end.last().unwrap().botright.y;
+    // Now: run a 1D line-scan in the y-axis across these rectangles
+    // Every time we encounter a rectangle, we increment a counter
+    // Every time we go beyond the bounds of a rectangle, we decrement it
+    // If the counter is >= 2, we have an intersection area: count it
+    for sweep_y in lowest_y..(highest_y + 1) {
+        // println!("Checking sweep_y = {}", sweep_y);
+
+
+        // Remove rects past the sweep line
+        loop {
+            if rects_end_idx == rects_end.len() { break; }
+
+            if sweep_y == rects_end[rects_end_idx].botright.y {
+                current_num_overlap -= 1;
+                rects_end_idx += 1;
+            } else {  break; }
+        }
+
+        // Add rects that are in the sweep line
+        loop {
+            if rects_idx == rects.len() { break; }
+
+            if sweep_y == rects[rects_idx].topleft.y {
+                current_num_overlap += 1;
+                rects_idx += 1;
+            } else { break; }
+        }
+
+        // After the adjustments: add this y coord to the intersection sum if 
+        // we have >= 2 intersections here
+        if current_num_overlap >= 2 {
+             total_intersection_distance += 1; 
+             // println!("Increased overlap at sweep_y: {}", sweep_y);
+        }
+    }
+    // Return the intersected distance we've accumulated
+    total_intersection_distance
+}
+
+// line_number is the 1-based position of this line in the input, for error reporting
+pub fn line_to_rect(line: &str, line_number: usize) -> Result<Rect> {
+    parse_claim(line, line_number).map(|(rect, _)| rect)
+}
+
+// The claim on this line (see parse_rects for the format) and the column of its id
+fn parse_claim(line: &str, line_number: usize) -> Result<(Rect, usize)> {
+    // Input format: #123 @ 3,2: 5x4
+    //              ==> Claim 123, 3 units from left, 2 units from top, 5 wide, 4 tall
+    let mut scanner = Scanner::new(line, line_number);
+    scanner.skip_whitespace();
+    scanner.optional("#");
+    scanner.skip_whitespace();
+    let id_column = scanner.column();
+    let claim: i32 = scanner.int()?;
+    separator(&mut scanner, "@")?;
+    let top_x: i32 = scanner.int()?;
+    separator(&mut scanner, ",")?;
+    let top_y: i32 = scanner.int()?;
+    separator(&mut scanner, ":")?;
+    let bot_x = far_side(&mut scanner, top_x, "wide")?;
+    scanner.skip_whitespace();
+    if !scanner.optional("x") && !scanner.optional("X") {
+        return Err(scanner.error("expected \"x\"").into());
+    }
+    scanner.skip_whitespace();
+    let bot_y = far_side(&mut scanner, top_y, "tall")?;
+    scanner.skip_whitespace();
+    scanner.end()?;
+    Ok((Rect { claim, topleft: Point{x: top_x, y: top_y}, botright: Point{x: bot_x, y: bot_y}}, id_column))
+}
+
+// The expected text, with any whitespace around it
+fn separator(scanner: &mut Scanner, expected: &str) -> Result<()> {
+    scanner.skip_whitespace();
+    scanner.literal(expected)?;
+    scanner.skip_whitespace();
+    Ok(())
+}
+
+// Reads a width or height (at least 1) and returns where the claim ends, from where it starts
+fn far_side(scanner: &mut Scanner, start: i32, direction: &str) -> Result<i32> {
+    let at = scanner.error(&format!("claims must be at least 1 {}", direction));
+    let size: i32 = scanner.int()?;
+    if size < 1 {
+        return Err(at.into());
+    }
+    start
+        .checked_add(size)
+        .ok_or_else(|| ParseError { reason: "claim ends out of range".to_string(), ..at }.into())
+}
+
+
+// Amazing viz at https://silentmatt.com/rectangle-intersection/
+// Returns the rectangle that represents the intersections between these 2 rectangles
+pub fn rectangular_intersection(r1: &Rect, r2: &Rect) -> Option<Rect> {
+    if r1.topleft.x < r2.botright.x && r1.botright.x > r2.topleft.x &&
+        r1.topleft.y < r2.botright.y && r1.botright.y > r2.topleft.y {
+            // Intersection
+            let intersect_top_left_x = std::cmp::max(r1.topleft.x, r2.topleft.x);
+            let intersect_top_left_y = std::cmp::max(r1.topleft.y, r2.topleft.y);
+            let intersect_bot_right_x = std::cmp::min(r1.botright.x, r2.botright.x);
+            let intersect_bot_right_y = std::cmp::min(r1.botright.y, r2.botright.y);
+            Some(Rect { claim: -1, topleft: Point{x: intersect_top_left_x, y: intersect_top_left_y}, botright: Point{x: intersect_bot_right_x, y: intersect_bot_right_y}})
+        }
+        else {
+            None
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_rects;
+    use aoc_common::Error;
+
+    // Every bad line of the fixture is reported, each against its own line, duplicates included
+    #[test]
+    fn reports_every_bad_line() {
+        let errors = match parse_rects(include_str!("../input_bad.txt")) {
+            Err(Error::Parses(errors)) => errors,
+            other => panic!("expected several parse errors, got {:?}", other.map(|rects| rects.len())),
+        };
+        let found: Vec<(usize, usize, &str)> = errors.iter().map(|e| (e.line, e.column, e.reason.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, 2, "claim #1 was already given (as \"#1 @ 1,1: 2x2\")"),
+                (4, 11, "claims must be at least 1 wide"),
+                (5, 6, "expected an integer"),
+                (7, 2, "claim #2 was already given (as \"#2@3,3:2X2\")"),
+            ]
+        );
+    }
+
+    #[test]
+    fn one_bad_line_is_one_error() {
+        match parse_rects("#1 @ 1,1: 2x2\n#1 @ 2,2: 1x1") {
+            Err(Error::Parse(e)) => {
+                assert_eq!((e.line, e.reason.as_str()), (2, "claim #1 was already given (as \"#1 @ 1,1: 2x2\")"))
+            }
+            other => panic!("expected one parse error, got {:?}", other.map(|rects| rects.len())),
+        }
+    }
+}
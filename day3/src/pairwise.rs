@@ -0,0 +1,40 @@
+use aoc_common::{Answer, Result};
+use std::collections::HashSet;
+use std::vec::Vec;
+
+use {parse_rects, rectangular_intersection, Point, Rect};
+
+/*
+    The first approach to part 1 (before the line sweep)
+
+    - Find the intersection of every pair of rectangles: O(n^2)
+    - The overlap area is the geometric union of all these intersections
+      --> computed here by marking every square inch covered by some intersection
+*/
+pub fn overlap_area(rects: &[Rect]) -> u32 {
+    // List of all rectangles representing the intersections between input list of rectangles
+    let mut all_intersections: Vec<Rect> = Vec::new();
+    for (i, rect) in rects.iter().enumerate() {
+        let rest = &rects[i+1..];
+        for r2 in rest {
+            if let Some(r) = rectangular_intersection(rect, r2) {
+                all_intersections.push(r);
+            }
+        }
+    }
+
+    // Now we need to find the geometric union of all these rectangles
+    let mut covered: HashSet<Point> = HashSet::new();
+    for r in &all_intersections {
+        for x in r.topleft.x..r.botright.x {
+            for y in r.topleft.y..r.botright.y {
+                covered.insert(Point { x, y });
+            }
+        }
+    }
+    covered.len() as u32
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(overlap_area(&parse_rects(input)?).into())
+}
@@ -0,0 +1,52 @@
+use aoc_common::random::{Options, Rng};
+use chrono::prelude::*;
+use chrono::Duration;
+use std::cmp;
+
+const DEFAULT_SHIFTS: usize = 300;
+const MAX_NAPS: i64 = 3;
+
+/*
+    A shuffled guard log of size shifts, one shift a night starting on 1518-01-01
+
+    Each shift begins between 23:45 the night before and 00:05, and the guard then takes
+    up to 3 naps between 00:06 and 00:59 (always at least one in the first shift, so some guard
+    is always asleep at some point). There are about one fifteenth as many guards as shifts, at least 2
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = cmp::max(options.size.unwrap_or(DEFAULT_SHIFTS), 1);
+
+    let mut ids: Vec<u32> = Vec::new();
+    while ids.len() < cmp::max(size / 15, 2) {
+        let id = rng.range(10, 3499) as u32;
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    let first_night = Utc.with_ymd_and_hms(1518, 1, 1, 0, 0, 0).unwrap();
+    let mut lines: Vec<String> = Vec::new();
+    for shift in 0..size {
+        let midnight = first_night + Duration::days(shift as i64);
+        let begins = midnight + Duration::minutes(rng.range(-15, 5));
+        lines.push(format!("[{}] Guard #{} begins shift\n", begins.format("%Y-%m-%d %H:%M"), rng.pick(&ids)));
+
+        // Falling asleep and waking up alternate, at distinct minutes in order
+        let naps = if shift == 0 { rng.range(1, MAX_NAPS) } else { rng.range(0, MAX_NAPS) };
+        let mut minutes: Vec<i64> = Vec::new();
+        while minutes.len() < 2 * naps as usize {
+            let minute = rng.range(6, 59);
+            if !minutes.contains(&minute) {
+                minutes.push(minute);
+            }
+        }
+        minutes.sort();
+        for (i, minute) in minutes.iter().enumerate() {
+            let event = if i % 2 == 0 { "falls asleep" } else { "wakes up" };
+            let time = midnight + Duration::minutes(*minute);
+            lines.push(format!("[{}] {}\n", time.format("%Y-%m-%d %H:%M"), event));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
@@ -0,0 +1,292 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate chrono;
+
+use aoc_common::parse::Scanner;
+use aoc_common::{Answer, Error, Extras, ParseError, Result, Solution};
+use chrono::prelude::*;
+use chrono::Duration;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::vec::Vec;
+
+pub mod generate;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogEvent {
+    WakeUp,
+    FallAsleep,
+    BeginShift(u32),
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Utc>,
+    pub event: LogEvent,
+    // Where the event was in the input (line and column from 1), for errors found once the entries are sorted
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub start_sleep: DateTime<Utc>,
+    pub end_sleep: DateTime<Utc>,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Guard {
+    pub id: u32,
+    pub sleep_intervals: Vec<Interval>,
+}
+
+impl std::fmt::Debug for LogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "[{}] {:?}", self.timestamp, self.event)
+    }
+}
+
+impl std::fmt::Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "<{} - {}>", self.start_sleep, self.end_sleep)
+    }
+}
+
+impl std::fmt::Debug for Guard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "#{} {:?}", self.id, self.sleep_intervals)
+    }
+}
+
+/*
+    Given a security log, process the period that guards are sleeping
+    - Need to sort the log entries by time before further processing
+
+*/
+pub fn parse_guards(input: &str) -> Result<Vec<Guard>> {
+    // Parse all log entries
+    let mut logentries: Vec<LogEntry> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line_to_logentry(line, i + 1))
+        .collect::<Result<_>>()?;
+
+    // Sort them by timestamp ordering
+    logentries.sort();
+    trace!("{:?}", logentries);
+
+    // Parse the sorted log entries into a set of Guard objects
+    let guards: Vec<Guard> = guards_from_sorted_logentries(&logentries)?;
+    trace!("{:?}", guards);
+    Ok(guards)
+}
+
+// Part 1: the guard with the most minutes asleep, and the minute they are most often asleep
+// Returns (guard id, minute)
+pub fn sleepiest_guard_minute(guards: &[Guard]) -> Option<(u32, u32)> {
+    // Sum up all sleep intervals to find the sleepiest guard
+    let max_guard: (&Guard, Duration) = guards
+        .iter()
+        .map(|g| {
+            (
+                g,
+                g.sleep_intervals
+                    .iter()
+                    .map(|s| s.end_sleep.signed_duration_since(s.start_sleep))
+                    .fold(Duration::zero(), |sum, i| sum + i),
+            )
+        })
+        // Ties go to the lowest guard id (guards are sorted by id)
+        .max_by_key(|&(g, asleep)| (asleep, Reverse(g.id)))?;
+
+    info!("Max guard: #{} asleep for {} minutes", max_guard.0.id, max_guard.1.num_minutes());
+
+    // Given the guard with the most sleep duration: we can find out the minutes he's asleep
+    let minute_ranges: Vec<(u32, u32)> = max_guard.0.sleep_intervals.iter().map(|i| (i.start_sleep.minute(), i.end_sleep.minute())).collect(); 
+
+    trace!("Minute ranges for guard: {:?}", minute_ranges);
+
+    let minute_sleep_frequencies = minute_frequencies(&minute_ranges);
+
+    trace!("Minute frequencies: {:?}", minute_sleep_frequencies);
+    let (max_minute, max_minute_freq) = most_frequent_minute(&minute_sleep_frequencies)?;
+    info!("(Max minute, Frequency): {:?}", (max_minute, max_minute_freq));
+
+    Some((max_guard.0.id, max_minute))
+}
+
+// PART 2: the guard that is most frequently asleep on the same minute
+// Returns (guard id, minute)
+pub fn most_frequent_guard_minute(guards: &[Guard]) -> Option<(u32, u32)> {
+    // Given the guard with the most sleep duration: we can find out the minutes he's asleep
+    let guards_minute_ranges: Vec<(u32, Vec<(u32, u32)>)> = guards.iter().map(|g| (g.id, g.sleep_intervals.iter().map(|i| (i.start_sleep.minute(), i.end_sleep.minute())).collect())).collect(); 
+
+    trace!("All minute ranges: {:?}", guards_minute_ranges);
+
+    let mut max_guard: Option<(u32, u32)> = None;
+    let mut max_minutefreq_overall: u32 = 0;
+    for (guardid, minute_ranges) in guards_minute_ranges {
+        let minute_sleep_frequencies = minute_frequencies(&minute_ranges);
+
+        // Guards come by increasing id, so only a strictly higher frequency replaces the one found so far
+        if let Some((max_minute, max_minute_freq)) = most_frequent_minute(&minute_sleep_frequencies) {
+            if max_minute_freq > max_minutefreq_overall {
+                max_minutefreq_overall = max_minute_freq;
+                max_guard = Some((guardid, max_minute));
+            }
+        }
+    }
+
+    if let Some((max_guardid, max_minute_overall)) = max_guard {
+        info!("Max guardid: {}, max minute freq: {}, max minute: {}, answer: {}", max_guardid, max_minutefreq_overall, max_minute_overall, max_guardid * max_minute_overall);
+    }
+    max_guard
+}
+
+// How many times each minute is covered by the [start, end) minute ranges
+fn minute_frequencies(minute_ranges: &[(u32, u32)]) -> BTreeMap<u32, u32> {
+    let mut minute_sleep_frequencies: BTreeMap<u32, u32> = BTreeMap::new();
+    for (start, end) in minute_ranges {
+        for i in *start..*end {
+            minute_sleep_frequencies.entry(i).and_modify(|x| *x += 1).or_insert(1);
+        }
+    }
+    minute_sleep_frequencies
+}
+
+// (minute, frequency) of the minute covered most often, the earliest one on a tie
+fn most_frequent_minute(minute_sleep_frequencies: &BTreeMap<u32, u32>) -> Option<(u32, u32)> {
+    minute_sleep_frequencies
+        .iter()
+        .max_by_key(|&(minute, frequency)| (*frequency, Reverse(*minute)))
+        .map(|(minute, frequency)| (*minute, *frequency))
+}
+
+pub struct ReposeRecord;
+
+// The (guard id, minute) that a part multiplies together: both the answer and its extras come from here,
+// so they always agree
+fn chosen_guard_minute(guards: &[Guard], part: u32) -> Result<(u32, u32)> {
+    let chosen = match part {
+        1 => sleepiest_guard_minute(guards),
+        _ => most_frequent_guard_minute(guards),
+    };
+    chosen.ok_or_else(|| Error::NoAnswer("no guard ever falls asleep".to_string()))
+}
+
+impl Solution for ReposeRecord {
+    type Input = Vec<Guard>;
+
+    fn parse(input: &str) -> Result<Vec<Guard>> {
+        parse_guards(input)
+    }
+
+    // The answer for both parts is guard id * minute
+    fn part1(guards: &Vec<Guard>) -> Result<Answer> {
+        let (id, minute) = chosen_guard_minute(guards, 1)?;
+        Ok((id * minute).into())
+    }
+
+    fn part2(guards: &Vec<Guard>) -> Result<Answer> {
+        let (id, minute) = chosen_guard_minute(guards, 2)?;
+        Ok((id * minute).into())
+    }
+
+    // The guard and minute that were multiplied together
+    fn extras(guards: &Vec<Guard>, part: u32) -> Result<Extras> {
+        let (id, minute) = chosen_guard_minute(guards, part)?;
+        Ok(vec![("guard", id.into()), ("minute", minute.into())])
+    }
+}
+
+// line_number is the 1-based position of this line in the input, for error reporting
+pub fn line_to_logentry(line: &str, line_number: usize) -> Result<LogEntry> {
+    // Get datetime for log entry: [1518-11-01 00:00]
+    let mut scanner = Scanner::new(line, line_number);
+    scanner.literal("[")?;
+    let datetime_error = scanner.error("not a valid date and time");
+    let year: i32 = scanner.int()?;
+    scanner.literal("-")?;
+    let month: u32 = scanner.int()?;
+    scanner.literal("-")?;
+    let day: u32 = scanner.int()?;
+    scanner.literal(" ")?;
+    let hour: u32 = scanner.int()?;
+    scanner.literal(":")?;
+    let min: u32 = scanner.int()?;
+    let datetime = Utc
+        .with_ymd_and_hms(year, month, day, hour, min, 0)
+        .single()
+        .ok_or(datetime_error)?;
+    scanner.literal("] ")?;
+
+    // Parse type of log entry
+    let column = scanner.column();
+    let event: LogEvent = if scanner.optional("falls asleep") {
+        LogEvent::FallAsleep
+    } else if scanner.optional("wakes up") {
+        LogEvent::WakeUp
+    } else if scanner.optional("Guard #") {
+        let guard_number: u32 = scanner.int()?;
+        scanner.literal(" begins shift")?;
+        LogEvent::BeginShift(guard_number)
+    } else {
+        return Err(scanner.error("expected \"falls asleep\", \"wakes up\" or \"Guard #\"").into());
+    };
+    scanner.end()?;
+
+    Ok(LogEntry {
+        timestamp: datetime,
+        event,
+        line: line_number,
+        column,
+        text: line.to_string(),
+    })
+}
+
+// Every nap has to be inside some guard's shift, and has to start before it ends
+pub fn guards_from_sorted_logentries(logentries: &[LogEntry]) -> Result<Vec<Guard>> {
+    let mut guards: Vec<Guard> = Vec::new();
+    // Sorted by guard id, so that ties between guards always go the same way
+    let mut guard_intervals: BTreeMap<u32, Vec<Interval>> = BTreeMap::new();
+    let mut current_guard_id: Option<u32> = None;
+    let mut start_sleep: Option<DateTime<Utc>> = None;
+    for logentry in logentries {
+        let error = |reason: &str| ParseError::new(logentry.line, logentry.column, &logentry.text, reason);
+        match logentry.event {
+            /*
+            LogEvent::BeginShift(id) => { guards.push(current_guard); current_guard = Guard { id: id, sleep_intervals: Vec::new() };}
+            LogEvent::FallAsleep => { start_sleep = logentry.timestamp; }
+            LogEvent::WakeUp => { current_guard.sleep_intervals.push(Interval { start_sleep: start_sleep, end_sleep: logentry.timestamp }) }
+            */
+            LogEvent::BeginShift(id) => {
+                current_guard_id = Some(id);
+            }
+            LogEvent::FallAsleep => {
+                current_guard_id.ok_or_else(|| error("no guard has begun a shift yet"))?;
+                start_sleep = Some(logentry.timestamp);
+            }
+            LogEvent::WakeUp => {
+                let id = current_guard_id.ok_or_else(|| error("no guard has begun a shift yet"))?;
+                let start_sleep = start_sleep.take().ok_or_else(|| error("the guard is not asleep"))?;
+                guard_intervals
+                    .entry(id)
+                    .or_default()
+                    .push(Interval {
+                        start_sleep,
+                        end_sleep: logentry.timestamp,
+                    });
+            }
+        }
+    }
+
+    for (id, intervals) in guard_intervals {
+        guards.push(Guard {
+            id,
+            sleep_intervals: intervals,
+        });
+    }
+
+    Ok(guards)
+}
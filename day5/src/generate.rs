@@ -0,0 +1,42 @@
+use aoc_common::random::{Options, Rng};
+
+const DEFAULT_UNITS: usize = 50000;
+const DEFAULT_DENSITY: f64 = 0.3;
+
+/*
+    A polymer of size units on a single line
+
+    density is the chance that a unit is the opposite polarity of the unit before it (and so reacts
+    with it straight away). Otherwise it is a random unit that does not react with the one before
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size.unwrap_or(DEFAULT_UNITS);
+    let density = options.density.unwrap_or(DEFAULT_DENSITY);
+
+    let mut polymer = String::with_capacity(size + 1);
+    let mut previous: Option<char> = None;
+    for _ in 0..size {
+        let unit = match previous {
+            Some(p) if rng.chance(density) => opposite_polarity(p),
+            _ => loop {
+                let c = rng.lowercase();
+                let c = if rng.chance(0.5) { c.to_ascii_uppercase() } else { c };
+                if previous != Some(opposite_polarity(c)) {
+                    break c;
+                }
+            },
+        };
+        polymer.push(unit);
+        previous = Some(unit);
+    }
+    polymer.push('\n');
+    polymer
+}
+
+fn opposite_polarity(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c.to_ascii_lowercase()
+    }
+}
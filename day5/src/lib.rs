@@ -0,0 +1,125 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate linked_list;
+
+use aoc_common::solution;
+use aoc_common::{Answer, Implementation, ParseError, Result, Solution};
+use linked_list::*;
+use std::vec::Vec;
+
+pub mod generate;
+pub mod stack;
+
+/*
+    Given a long string of characters, adjacent letters of different cases cancel out.
+    The same algorithm is applied to the resultant string until no further actions are possible.
+    What is the resultant string given any input string?
+
+    Issue in Rust is that I cannot find a way to manipulate the data structure as I am going through it  (like holding a raw pointer in C) in standard Rust. Would like to avoid copying to new lists multiple times (although functions like .drain() allow the reuse of the space)
+
+    Using external linked-list with "Cursor" API: the Cursor actually points "in-between" two elements, which allows modifications of the list as required
+
+*/
+
+// The polymer is a single line of letters
+pub fn parse_polymer(input: &str) -> Result<String> {
+    let polymer_string = input.trim_end();
+    for (i, line) in polymer_string.lines().enumerate() {
+        if i > 0 {
+            return Err(ParseError::new(i + 1, 1, line, "the polymer must be on a single line").into());
+        }
+        if let Some(pos) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+            let column = line[..pos].chars().count() + 1;
+            return Err(ParseError::new(i + 1, column, line, "polymer units must be letters").into());
+        }
+    }
+    Ok(polymer_string.to_string())
+}
+
+// Part 2: remove all units of one type (both cases) and react, keeping the shortest result
+pub fn shortest_improved_length(polymer_string: &str) -> i32 {
+    let strings_to_process: Vec<String> = "abcdefghijklmnopqrstuvwxyz"
+        .chars()
+        .map(|c| {
+            polymer_string
+                .chars()
+                .filter(|c1| *c1 != c && *c1 != c.to_ascii_uppercase())
+                .collect()
+        })
+        .collect();
+
+    let mut shortest_len = i32::MAX;
+    for s in strings_to_process {
+        shortest_len  = std::cmp::min(get_reaction_char_length(&s), shortest_len);
+    }
+    shortest_len
+}
+
+pub struct Polymer;
+
+// Reacting with the linked list cursor, or with a Vec used as a stack
+pub const IMPLEMENTATIONS: &[Implementation] = &[
+    Implementation { name: "linked-list", part: 1, solve: solution::part1::<Polymer> },
+    Implementation { name: "stack", part: 1, solve: stack::part1 },
+    Implementation { name: "linked-list", part: 2, solve: solution::part2::<Polymer> },
+    Implementation { name: "stack", part: 2, solve: stack::part2 },
+];
+
+impl Solution for Polymer {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        parse_polymer(input)
+    }
+
+    fn part1(polymer_string: &String) -> Result<Answer> {
+        Ok(get_reaction_char_length(polymer_string).into())
+    }
+
+    fn part2(polymer_string: &String) -> Result<Answer> {
+        Ok(shortest_improved_length(polymer_string).into())
+    }
+}
+
+pub fn get_reaction_char_length(polymer_string: &str) -> i32 {
+    // This is the forward direction: we keep trying to add chars to the linked list
+    // and if we have a "reaction" with a previous element, backtrack.
+    let mut polymer: LinkedList<char> = LinkedList::new();
+    let mut polymer_cursor = polymer.cursor();
+    let mut previous_char: char = ' ';
+    for c in polymer_string.chars() {
+        // println!("Input char: {}", c);
+        // Continue to loop until no more backwards reactions happen
+        if causes_reaction(c, previous_char) {
+            // Don't push this char into the list, and remove the previous char
+            polymer_cursor.prev();
+            polymer_cursor.remove();
+            // println!("Reaction: {} and {}", previous_char, c);
+            previous_char = *polymer_cursor.peek_prev().unwrap_or(&mut ' ');
+        } else {
+            polymer_cursor.insert(c);
+            polymer_cursor.next();
+            // println!("Insert: {}", c);
+            previous_char = c;
+        }
+    }
+
+    polymer_cursor.reset();
+
+    let mut count = 0;
+    let mut reacted = String::new();
+    while let Some(c) = polymer_cursor.next() {
+        reacted.push(*c);
+        count += 1;
+    }
+    trace!("{}", reacted);
+    info!("Total # chars: {}", count);
+
+    count
+}
+
+pub fn causes_reaction(a: char, b: char) -> bool {
+    // Must be opposite cases, and otherwise equal
+    ((a.is_lowercase() && b.is_uppercase()) || (a.is_uppercase() && b.is_lowercase()))
+        && (a.eq_ignore_ascii_case(&b))
+}
@@ -0,0 +1,42 @@
+use aoc_common::{Answer, Result};
+use std::vec::Vec;
+
+use {causes_reaction, parse_polymer};
+
+/*
+    The same forward pass as get_reaction_char_length, but with a Vec used as a stack
+    instead of the linked list cursor: the only element we ever remove is the last one pushed
+*/
+pub fn get_reaction_char_length(polymer_string: &str) -> usize {
+    let mut stack: Vec<char> = Vec::with_capacity(polymer_string.len());
+    for c in polymer_string.chars() {
+        match stack.last() {
+            Some(&previous_char) if causes_reaction(c, previous_char) => { stack.pop(); }
+            _ => stack.push(c),
+        }
+    }
+    stack.len()
+}
+
+// Part 2: remove all units of one type (both cases) and react, keeping the shortest result
+pub fn shortest_improved_length(polymer_string: &str) -> usize {
+    "abcdefghijklmnopqrstuvwxyz"
+        .chars()
+        .map(|c| {
+            let s: String = polymer_string
+                .chars()
+                .filter(|c1| !c1.eq_ignore_ascii_case(&c))
+                .collect();
+            get_reaction_char_length(&s)
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(get_reaction_char_length(&parse_polymer(input)?).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(shortest_improved_length(&parse_polymer(input)?).into())
+}
@@ -0,0 +1,66 @@
+use aoc_common::{Answer, Error, Result};
+use binary_heap_plus::*;
+use petgraph::algo::toposort;
+use petgraph::graphmap::DiGraphMap;
+use std::collections::HashSet;
+use std::vec::Vec;
+
+use parse_graph;
+
+/*
+    The first attempt at part 1 (originally main.failedattempt.rs)
+
+    Start from the first node of a toposort, then keep a min-heap frontier of the neighbours
+    of visited nodes, always taking the alphabetically first one whose prereqs are all visited.
+    It goes wrong when several steps have no prereqs: only the first of them is ever put in
+    the frontier, so the order is wrong or stops early (returned as is).
+*/
+pub fn step_order(g: &DiGraphMap<char, i8>) -> Option<String> {
+    // Run a toposort so that we can start from the node with 0 incoming edges (no deps)
+    let topo_nodes = toposort(g, None).ok()?;
+
+    let mut visited: HashSet<char> = HashSet::new();
+    let mut frontier: BinaryHeap<char, MinComparator> = BinaryHeap::new_min();
+    let mut cur_node = *topo_nodes.first()?;
+    let mut order = String::new();
+
+    loop {
+        order.push(cur_node);
+        visited.insert(cur_node);
+        if visited.len() == g.node_count() { break; }
+
+        // List all neighbors that aren't visited yet and aren't in the frontier
+        let mut new_nbrs: Vec<char> = g.neighbors_directed(cur_node, petgraph::Direction::Outgoing).filter(|n| !visited.contains(n) && !frontier.iter().any(|x| x == n)).collect();
+        new_nbrs.sort();
+
+        // Expand sorted frontier to contain only non-visited nodes
+        frontier.extend(&new_nbrs);
+
+        // Move on to alphabetically next node in frontier
+        // We loop to make sure we are selecting a candidate that has all pre-reqs fulfilled
+        let mut not_fulfulled_prereq: Vec<char> = Vec::new();
+        loop {
+            // Ran out of candidates: this is where the attempt fails
+            let candidate = match frontier.pop() {
+                Some(candidate) => candidate,
+                None => return Some(order),
+            };
+            // Our visited set must contain all of these nodes, otherwise, try next node
+            if visited.is_superset(&g.neighbors_directed(candidate, petgraph::Direction::Incoming).collect()) {
+                frontier.extend(&not_fulfulled_prereq);
+                cur_node = candidate;
+                break;
+            } else {
+                not_fulfulled_prereq.push(candidate);
+            }
+        }
+    }
+
+    Some(order)
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    step_order(&parse_graph(input)?)
+        .map(Answer::from)
+        .ok_or_else(|| Error::NoAnswer("the steps contain a cycle".to_string()))
+}
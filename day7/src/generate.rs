@@ -0,0 +1,37 @@
+use aoc_common::random::{Options, Rng};
+
+// Steps are single letters, so there can be at most 26
+const MAX_STEPS: usize = 26;
+// Chance of each extra edge, on top of the one that connects every step
+const EXTRA_EDGE_CHANCE: f64 = 0.15;
+
+/*
+    "Step X must be finished before step Y can begin." lines over size steps (2 to 26, all by default),
+    in random order
+
+    The steps are put in a hidden random order and every edge goes forwards in it, so the graph is
+    always acyclic. Every step after the first in that order has at least one prerequisite,
+    so every step appears in some line
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = options.size.unwrap_or(MAX_STEPS).clamp(2, MAX_STEPS);
+
+    let mut order: Vec<char> = (b'A'..=b'Z').map(|b| b as char).collect();
+    rng.shuffle(&mut order);
+    order.truncate(size);
+
+    let mut lines: Vec<String> = Vec::new();
+    for after in 1..size {
+        let required = rng.below(after as u64) as usize;
+        for before in 0..after {
+            if before == required || rng.chance(EXTRA_EDGE_CHANCE) {
+                lines.push(format!(
+                    "Step {} must be finished before step {} can begin.\n",
+                    order[before], order[after]
+                ));
+            }
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
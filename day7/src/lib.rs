@@ -0,0 +1,195 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate binary_heap_plus;
+extern crate petgraph;
+
+use aoc_common::parse::Scanner;
+use aoc_common::solution;
+use aoc_common::{Answer, Error, Extras, Implementation, Result, Solution};
+use petgraph::algo::toposort;
+use petgraph::graphmap::DiGraphMap;
+use std::collections::{HashSet};
+use std::vec::Vec;
+
+pub mod frontier;
+pub mod generate;
+
+#[derive(Debug, Clone, Copy)]
+struct WorkerStatus {
+    task: Option<char>,
+    timeremaining: u32 
+}
+
+// Both parts assume there is at least one step and that the steps can all be done, so a graph
+// without steps or with a cycle is turned away here
+pub fn parse_graph(input: &str) -> Result<DiGraphMap<char, i8>> {
+    let mut g = DiGraphMap::new();
+
+    // Parse all log entries
+    for (i, line_parse) in input.lines().enumerate() {
+        let (parent, child): (char, char) = line_to_edge(line_parse, i + 1)?;
+        trace!("{} -> {}", parent, child);
+        g.add_edge(parent, child, -1);
+    }
+    if g.node_count() == 0 {
+        return Err(Error::NoAnswer("there are no steps".to_string()));
+    }
+    if let Err(cycle) = toposort(&g, None) {
+        return Err(Error::NoAnswer(format!("the steps contain a cycle (through step {})", cycle.node_id())));
+    }
+    Ok(g)
+}
+
+// Part 1: visit the steps in order, always picking the alphabetically first step with all prereqs done
+pub fn step_order(g: &DiGraphMap<char, i8>) -> String {
+    let mut visited: HashSet<char> = HashSet::new();
+    let mut order = String::new();
+
+    loop {
+        let mut cur_satisfied_nodes = find_all_prereq_satisfied_alphabetical(g, &mut visited, &[]);
+        if let Some(cur_node) = cur_satisfied_nodes.pop() {
+            visited.insert(cur_node);
+            order.push(cur_node);
+        } else {
+            break;
+        }
+    }
+    order
+}
+
+// Part 2: time taken for 5 workers to complete all steps, where step X takes 60 + X seconds (A = 1)
+pub fn time_to_complete(g: &DiGraphMap<char, i8>) -> u32 {
+    let mut visited: HashSet<char> = HashSet::new();
+    // Add all neighbors to the frontier: will be sorted by char
+    // println!("{:?}", cur_satisfied_nodes);
+
+    // Initialize 5 workers that can pick up tasks
+    let mut worker_status_xs = [WorkerStatus {task: None, timeremaining: 0}; 5];
+    let mut total_time_taken = 0;
+
+    // If there is a free worker, give them a job to do from the list of current satisfied nodes
+    loop {
+        let mut cur_satisfied_nodes = find_all_prereq_satisfied_alphabetical(g, &mut visited, &worker_status_xs);
+        
+        // Nothing left to search and everyone is done with their tasks
+        if cur_satisfied_nodes.is_empty() && find_free_worker(&mut worker_status_xs).is_none() { break; }
+
+        //println!("Satisfied Nodes: {:?}", cur_satisfied_nodes);
+        // Either we have nodes left to satisfy, or workers are currently in use
+        while find_free_worker(&mut worker_status_xs).is_some() && !cur_satisfied_nodes.is_empty() {
+            // Assign work to workers
+            // Only do something when we have a free worker and nodes to visit
+            // Give available work to workers (aka timeremaining = 0)
+            let worker = find_free_worker(&mut worker_status_xs).unwrap();
+            let new_task = cur_satisfied_nodes.pop().unwrap();
+            worker.task = Some(new_task);
+            worker.timeremaining = 61 + ((new_task as u32) - ('A' as u32));
+
+            //println!{"Worker assigned: {:?}", worker};
+        }
+
+        //println!("Pre-Step Worker Status: {:?}", worker_status_xs);
+        // At least 1 worker is busy
+        let (time_taken, completed_tasks) = step_workers(&mut worker_status_xs);
+        total_time_taken += time_taken;
+        // At least one worker has finished - need to add that to the visited nodes list
+        visited.extend(&completed_tasks);
+
+        //println!("Completed tasks: {:?}, \nWorker Status: {:?}\nTotal Time: {}\n", completed_tasks, worker_status_xs, total_time_taken);
+        trace!("t = {}: completed {}", total_time_taken, completed_tasks.iter().collect::<String>());
+
+
+        if visited.len() == g.node_count() { break; }
+    }
+
+    total_time_taken
+}
+
+pub struct Steps;
+
+// Part 1 by rescanning for satisfied steps each time, or with the first (broken) frontier attempt
+pub const IMPLEMENTATIONS: &[Implementation] = &[
+    Implementation { name: "satisfied-scan", part: 1, solve: solution::part1::<Steps> },
+    Implementation { name: "frontier", part: 1, solve: frontier::part1 },
+    Implementation { name: "workers", part: 2, solve: solution::part2::<Steps> },
+];
+
+impl Solution for Steps {
+    type Input = DiGraphMap<char, i8>;
+
+    fn parse(input: &str) -> Result<DiGraphMap<char, i8>> {
+        parse_graph(input)
+    }
+
+    fn part1(g: &DiGraphMap<char, i8>) -> Result<Answer> {
+        Ok(step_order(g).into())
+    }
+
+    fn part2(g: &DiGraphMap<char, i8>) -> Result<Answer> {
+        Ok(time_to_complete(g).into())
+    }
+
+    // Both parts report the step order and how long the workers take to get through it
+    fn extras(g: &DiGraphMap<char, i8>, _part: u32) -> Result<Extras> {
+        Ok(vec![
+            ("order", step_order(g).into()),
+            ("total_time", time_to_complete(g).into()),
+        ])
+    }
+}
+
+// Step C must be finished before step A can begin. ==> (C, A)
+// line_number is the 1-based position of this line in the input, for error reporting
+pub fn line_to_edge(line: &str, line_number: usize) -> Result<(char, char)> {
+    let mut scanner = Scanner::new(line, line_number);
+    scanner.literal("Step ")?;
+    let parent = step_name(&mut scanner)?;
+    scanner.literal(" must be finished before step ")?;
+    let child = step_name(&mut scanner)?;
+    scanner.literal(" can begin.")?;
+    scanner.end()?;
+    Ok((parent, child))
+}
+
+// Step durations are worked out from the letter, so only A-Z make sense
+fn step_name(scanner: &mut Scanner) -> Result<char> {
+    let error = scanner.error("expected a step name (A-Z)");
+    match scanner.char() {
+        Ok(c) if c.is_ascii_uppercase() => Ok(c),
+        _ => Err(error.into()),
+    }
+}
+
+// Search all nodes for those that have their parent nodes visited
+fn find_all_prereq_satisfied_alphabetical(g: &DiGraphMap<char, i8>, visited: &mut HashSet<char>, workers: &[WorkerStatus]) -> Vec<char> {
+    let all_nodes = g.nodes();
+    let all_nodes_prereqs = all_nodes.map(|n| (n, g.neighbors_directed(n, petgraph::Direction::Incoming).collect()));
+    // All incoming nodes are visited, and no worker is currently working on this task
+    let mut satisfied_nodes: Vec<char> = all_nodes_prereqs.filter(|(n, prereqs)| visited.is_superset(prereqs) && !visited.contains(n) && workers.iter().find(|w| w.task == Some(*n)).is_none()).map(|(n, _)| n).collect();
+
+    // Reverse sort so that we can pop later
+    satisfied_nodes.sort_unstable_by(|a, b| b.cmp(a));
+    satisfied_nodes
+}
+
+fn find_free_worker(workers: &mut [WorkerStatus]) -> Option<&mut WorkerStatus> {
+    workers.iter_mut().find(|w| w.task.is_none())
+}
+
+// Complete at least 1 worker's job, return the time it took and the tasks that were completed
+fn step_workers(workers: &mut [WorkerStatus]) -> (u32, Vec<char>) {
+    // Minumum time required to make 1 worker finish
+    let time_to_step = workers.iter().filter(|w| w.task.is_some()).min_by_key(|w| w.timeremaining).unwrap().timeremaining;
+
+    let mut completed_tasks = Vec::new();
+    for w in workers.iter_mut() {
+        if w.task.is_some() { 
+            w.timeremaining -= time_to_step;
+            if w.timeremaining == 0 {
+                completed_tasks.push(w.task.unwrap());
+                w.task = None;
+            }
+        }
+    }
+    (time_to_step, completed_tasks)
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use aoc_common::random::{Options, Rng};
+use std::cmp;
+
+const DEFAULT_NODES: usize = 2000;
+const MAX_CHILDREN: u64 = 5;
+const ZERO_METADATA: f64 = 0.05;
+
+/*
+    A license tree of exactly size nodes, written as the usual single line of numbers
+
+    Every node has 1 to 3 metadata entries. A leaf's entries are between 1 and 9; a node with children
+    mostly refers to its children (1 up to one past its last child), so part 2 has something to add up.
+    Now and then an entry is 0 instead, which a leaf adds nothing for and which refers to no child
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = cmp::max(options.size.unwrap_or(DEFAULT_NODES), 1);
+    let mut numbers: Vec<u64> = Vec::new();
+    node(rng, size, &mut numbers);
+
+    let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    numbers.join(" ") + "\n"
+}
+
+// Writes out a subtree of exactly nodes nodes (including its root)
+fn node(rng: &mut Rng, nodes: usize, numbers: &mut Vec<u64>) {
+    // Split the nodes below this one between the children at random cut points
+    let below = nodes - 1;
+    let mut cuts: Vec<usize> = vec![0, below];
+    if below > 1 {
+        let extra_cuts = rng.below(cmp::min(below as u64, MAX_CHILDREN));
+        for _ in 0..extra_cuts {
+            cuts.push(1 + rng.below(below as u64 - 1) as usize);
+        }
+    }
+    cuts.sort();
+    cuts.dedup();
+    let child_sizes: Vec<usize> = cuts.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let num_metadata = rng.range(1, 3) as u64;
+    numbers.push(child_sizes.len() as u64);
+    numbers.push(num_metadata);
+    let num_children = child_sizes.len() as i64;
+    for child in child_sizes {
+        node(rng, child, numbers);
+    }
+    let max_metadata = if num_children == 0 { 9 } else { num_children + 1 };
+    for _ in 0..num_metadata {
+        let entry = if rng.chance(ZERO_METADATA) { 0 } else { rng.range(1, max_metadata) };
+        numbers.push(entry as u64);
+    }
+}
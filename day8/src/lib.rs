@@ -0,0 +1,104 @@
+extern crate aoc_common;
+
+use aoc_common::{parse, Answer, ParseError, Result, Solution};
+use std::vec::Vec;
+use std::slice::Iter;
+
+pub mod generate;
+
+#[derive(Debug)]
+pub struct Node {
+    pub nodeid: u32,
+    pub num_children: u32,
+    pub num_metadata: u32,
+    pub children: Vec<Node>,
+    pub metadata: Vec<u32>
+}
+
+// For each node: we have 4 steps
+// 1. Read # of child nodes
+// 2. Read # of metadata 
+// 3. If any, read child node info
+// 4. If any, read metadata info
+pub fn parse_tree(input: &str) -> Result<Node> {
+    let nums: Vec<u32> = parse::numbers(input)?;
+
+    let mut nodeid_cur: u32 = 0;
+    let mut iter = nums.iter();
+    let head_node = parse_node(&mut iter, &mut nodeid_cur)
+        .ok_or_else(|| ParseError::end_of_input(input, "the input ends in the middle of a node"))?;
+
+    let leftover = iter.count();
+    if leftover > 0 {
+        let reason = format!("{} numbers left over after the root node", leftover);
+        return Err(ParseError::end_of_input(input, &reason).into());
+    }
+    Ok(head_node)
+}
+
+pub struct LicenseTree;
+
+impl Solution for LicenseTree {
+    type Input = Node;
+
+    fn parse(input: &str) -> Result<Node> {
+        parse_tree(input)
+    }
+
+    fn part1(head_node: &Node) -> Result<Answer> {
+        Ok(sum_metadata(head_node).into())
+    }
+
+    fn part2(head_node: &Node) -> Result<Answer> {
+        Ok(part2_sum(head_node).into())
+    }
+}
+
+// None if we run out of numbers before the node is complete
+pub fn parse_node(iter: &mut Iter<u32>, nodeid_cur: &mut u32) -> Option<Node> {
+    let num_children = iter.next()?;
+    let num_metadata = iter.next()?;
+    let mut children: Vec<Node> = Vec::new();
+    let mut meta: Vec<u32> = Vec::new(); 
+    let my_nodeid: u32= *nodeid_cur;
+    for _ in 0..*num_children {
+        // Iterate through child nodes
+        *nodeid_cur += 1;
+        children.push(parse_node(iter, nodeid_cur)?);
+    }
+    for _ in 0..*num_metadata {
+        meta.push(*iter.next()?);
+    }
+    // println!("Node {}- #children: {}, #meta: {}, metadata: {:?}: children: {:?}", my_nodeid, num_children, num_metadata, meta, children);
+    Some(Node { nodeid: my_nodeid, num_children: *num_children, num_metadata: *num_metadata, children, metadata: meta })
+}
+
+pub fn sum_metadata(node: &Node) -> u32 {
+    let local_sum: u32 = node.metadata.iter().sum();
+    let mut children_sum = 0;
+
+    for cnode in node.children.iter() { children_sum += sum_metadata(cnode) }
+
+    local_sum + children_sum
+}
+
+/*
+If a node has no child nodes, its value is the sum of its metadata entries. So, the value of node B is 10+11+12=33, and the value of node D is 99.
+
+However, if a node does have child nodes, the metadata entries become indexes which refer to those child nodes. A metadata entry of 1 refers to the first child node, 2 to the second, 3 to the third, and so on. The value of this node is the sum of the values of the child nodes referenced by the metadata entries. If a referenced child node does not exist, that reference is skipped. A child node can be referenced multiple time and counts each time it is referenced. A metadata entry of 0 does not refer to any child node.
+*/
+pub fn part2_sum(node: &Node) -> u32 {
+    if node.num_children == 0 {
+        node.metadata.iter().sum()
+    } else {
+        let mut total_sum: u32 = 0;
+        for i in node.metadata.iter() {
+            // Entry 0 refers to no child at all
+            if let Some(n) = i.checked_sub(1).and_then(|child| node.children.get(child as usize)) {
+                total_sum += part2_sum(n);
+            }
+
+        }
+        total_sum
+    }
+}
\ No newline at end of file
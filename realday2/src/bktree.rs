@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use hamming_distance;
+
+/*
+    A BK-tree over box ids, for "which ids are close to this one?" queries against a big catalogue
+    (e.g. suggesting what a mistyped scan was meant to be)
+
+    Every child hangs off its parent at its distance from the parent, and since the distance is a metric
+    (triangle inequality), a search for ids within d of a query only needs to visit the children at
+    distance (parent distance - d) to (parent distance + d). Ids are kept in the order given and
+    answers refer to them by index. Duplicate ids are kept too, at distance 0 from each other
+*/
+// The distance between two ids given a limit: exactly when it is at most the limit, otherwise any number
+// above it, so that a slow metric can give up on ids that are far apart (see edit)
+pub type Metric = fn(&str, &str, u32) -> u32;
+
+// Hamming distance, which is cheap enough to always work out in full
+pub fn hamming(x: &str, y: &str, _limit: u32) -> u32 {
+    hamming_distance(x, y)
+}
+
+pub struct BkTree<'a> {
+    ids: Vec<&'a str>,
+    metric: Metric,
+    // children[i] holds (distance from ids[i], index of the child)
+    children: Vec<Vec<(u32, usize)>>,
+}
+
+// An id from the tree and its distance to the query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Match {
+    pub distance: u32,
+    pub index: usize,
+}
+
+impl<'a> BkTree<'a> {
+    // Hamming distance: the number of positions where the ids differ
+    pub fn new<S: AsRef<str>>(ids: &'a [S]) -> BkTree<'a> {
+        BkTree::with_metric(ids, hamming)
+    }
+
+    pub fn with_metric<S: AsRef<str>>(ids: &'a [S], metric: Metric) -> BkTree<'a> {
+        let mut tree = BkTree {
+            ids: Vec::with_capacity(ids.len()),
+            metric,
+            children: Vec::with_capacity(ids.len()),
+        };
+        for id in ids {
+            tree.insert(id.as_ref());
+        }
+        tree
+    }
+
+    fn insert(&mut self, id: &'a str) {
+        let index = self.ids.len();
+        self.ids.push(id);
+        self.children.push(Vec::new());
+        if index == 0 {
+            return;
+        }
+
+        // Walk down from the root along the edges at this id's distance until there is none
+        let mut node = 0;
+        loop {
+            // The edge to follow or add is at the exact distance
+            let distance = (self.metric)(self.ids[node], id, u32::MAX);
+            match self.children[node].iter().find(|(d, _)| *d == distance) {
+                Some(&(_, child)) => node = child,
+                None => {
+                    self.children[node].push((distance, index));
+                    return;
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn id(&self, index: usize) -> &'a str {
+        self.ids[index]
+    }
+
+    // How far a query can be from node before neither it nor any of its children can be within radius of
+    // the query: anything beyond that does not need an exact distance
+    fn limit(&self, node: usize, radius: u32) -> u32 {
+        let furthest = self.children[node].iter().map(|(d, _)| *d).max().unwrap_or(0);
+        furthest.saturating_add(radius)
+    }
+
+    // Every id within max_distance of the query, closest first
+    pub fn within(&self, query: &str, max_distance: u32) -> Vec<Match> {
+        let mut found: Vec<Match> = Vec::new();
+        let mut stack: Vec<usize> = if self.is_empty() { Vec::new() } else { vec![0] };
+        while let Some(node) = stack.pop() {
+            let distance = (self.metric)(self.ids[node], query, self.limit(node, max_distance));
+            if distance <= max_distance {
+                found.push(Match { distance, index: node });
+            }
+            let (low, high) = (distance.saturating_sub(max_distance), distance.saturating_add(max_distance));
+            stack.extend(
+                self.children[node]
+                    .iter()
+                    .filter(|(d, _)| low <= *d && *d <= high)
+                    .map(|(_, child)| *child),
+            );
+        }
+        found.sort();
+        found
+    }
+
+    // The k ids closest to the query, closest first (ties broken by index)
+    pub fn nearest(&self, query: &str, k: usize) -> Vec<Match> {
+        // The best k so far, worst on top, so the search radius can shrink as better ids turn up
+        let mut best: BinaryHeap<Match> = BinaryHeap::with_capacity(k + 1);
+        let mut stack: Vec<usize> = if self.is_empty() || k == 0 { Vec::new() } else { vec![0] };
+        while let Some(node) = stack.pop() {
+            let radius = if best.len() < k { u32::MAX } else { best.peek().map_or(u32::MAX, |worst| worst.distance) };
+            let distance = (self.metric)(self.ids[node], query, self.limit(node, radius));
+            let candidate = Match { distance, index: node };
+            if best.len() < k {
+                best.push(candidate);
+            } else if best.peek().map(|worst| candidate.cmp(worst)) == Some(Ordering::Less) {
+                best.pop();
+                best.push(candidate);
+            }
+
+            // Anything further than the current kth best cannot get in (children at the same
+            // distance as the kth best can still win on index)
+            let radius = if best.len() < k { u32::MAX } else { best.peek().map_or(u32::MAX, |worst| worst.distance) };
+            let (low, high) = (distance.saturating_sub(radius), distance.saturating_add(radius));
+            stack.extend(
+                self.children[node]
+                    .iter()
+                    .filter(|(d, _)| low <= *d && *d <= high)
+                    .map(|(_, child)| *child),
+            );
+        }
+        best.into_sorted_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BkTree, Match};
+    use aoc_common::random::Rng;
+    use edit::{self, Distance};
+    use hamming_distance;
+
+    // A metric worked out in full
+    type Exact = fn(&str, &str) -> u32;
+
+    fn random_id(rng: &mut Rng) -> String {
+        let len = rng.range(2, 6);
+        (0..len).map(|_| *rng.pick(&["a", "b", "c", "d"])).collect()
+    }
+
+    // Every id against the query, closest first and then by index
+    fn scan(ids: &[String], query: &str, distance: Exact) -> Vec<Match> {
+        let mut all: Vec<Match> =
+            ids.iter().enumerate().map(|(index, id)| Match { distance: distance(id, query), index }).collect();
+        all.sort();
+        all
+    }
+
+    #[test]
+    fn queries_match_linear_scan() {
+        let mut rng = Rng::new(11);
+        let ids: Vec<String> = (0..300).map(|_| random_id(&mut rng)).collect();
+        let queries: Vec<String> = (0..40).map(|_| random_id(&mut rng)).collect();
+        let metrics: &[(Distance, Exact)] = &[
+            (Distance::Hamming, hamming_distance),
+            (Distance::Levenshtein, edit::levenshtein),
+            (Distance::Damerau, edit::damerau),
+        ];
+        for &(metric, distance) in metrics {
+            let tree = BkTree::with_metric(&ids, metric.metric());
+            for query in &queries {
+                let all = scan(&ids, query, distance);
+                for radius in 0..4 {
+                    let expected: Vec<Match> = all.iter().cloned().filter(|m| m.distance <= radius).collect();
+                    assert_eq!(tree.within(query, radius), expected, "{:?} {} within {}", metric, query, radius);
+                }
+                for &k in &[0, 1, 5, 30, 400] {
+                    let expected: Vec<Match> = all.iter().cloned().take(k).collect();
+                    assert_eq!(tree.nearest(query, k), expected, "{:?} {} nearest {}", metric, query, k);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use unicode::{self, Interner};
+
+/*
+    A catalogue of box ids for prefix and pattern lookups: a trie over the ids' characters
+
+    Chains of nodes with one child each are merged into a single edge (a radix trie), which matters
+    because random ids stop sharing prefixes after a few characters: without merging, every character
+    of every id would be its own node. Each edge's characters are a slice of one of the ids (as
+    numbered by an Interner), so edges do not copy any text.
+
+    Queries may use ? for "any one character", in a prefix as well as in a whole id (abc?efg). A query
+    character that no id has cannot match anything. Answers are indexes into the catalogue, in order
+*/
+pub const WILDCARD: &str = "?";
+
+pub struct Catalogue<'a> {
+    ids: &'a [String],
+    characters: Interner,
+    // Every id as character numbers (edges point into these)
+    symbols: Vec<Vec<u32>>,
+    // nodes[0] is the root
+    nodes: Vec<Node>,
+}
+
+struct Node {
+    // The characters on the edge into this node: symbols[id][start..end]
+    id: usize,
+    start: usize,
+    end: usize,
+    // Ordered by the first character of their edge
+    children: Vec<usize>,
+    // Ids ending here
+    ends: Vec<usize>,
+}
+
+// One character of a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Query {
+    Any,
+    Is(u32),
+}
+
+impl<'a> Catalogue<'a> {
+    pub fn new(ids: &'a [String]) -> Catalogue<'a> {
+        let mut characters = Interner::new();
+        let symbols = ids.iter().map(|id| characters.intern(id)).collect();
+        let mut catalogue = Catalogue {
+            ids,
+            characters,
+            symbols,
+            nodes: vec![Node {
+                id: 0,
+                start: 0,
+                end: 0,
+                children: Vec::new(),
+                ends: Vec::new(),
+            }],
+        };
+        for id in 0..ids.len() {
+            catalogue.insert(id);
+        }
+        catalogue
+    }
+
+    fn label(&self, node: usize) -> &[u32] {
+        let node = &self.nodes[node];
+        &self.symbols[node.id][node.start..node.end]
+    }
+
+    fn insert(&mut self, id: usize) {
+        let len = self.symbols[id].len();
+        let (mut node, mut depth) = (0, 0);
+        loop {
+            if depth == len {
+                self.nodes[node].ends.push(id);
+                return;
+            }
+            let next = self.symbols[id][depth];
+            let found = self.nodes[node]
+                .children
+                .binary_search_by_key(&next, |&child| self.label(child)[0]);
+            match found {
+                Err(slot) => {
+                    let leaf = self.nodes.len();
+                    self.nodes.push(Node {
+                        id,
+                        start: depth,
+                        end: len,
+                        children: Vec::new(),
+                        ends: vec![id],
+                    });
+                    self.nodes[node].children.insert(slot, leaf);
+                    return;
+                }
+                Ok(slot) => {
+                    let child = self.nodes[node].children[slot];
+                    let shared = self
+                        .label(child)
+                        .iter()
+                        .zip(&self.symbols[id][depth..])
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    if shared < self.label(child).len() {
+                        // Split the edge where this id leaves it
+                        let middle = self.nodes.len();
+                        let (edge_id, start) = (self.nodes[child].id, self.nodes[child].start);
+                        self.nodes.push(Node {
+                            id: edge_id,
+                            start,
+                            end: start + shared,
+                            children: vec![child],
+                            ends: Vec::new(),
+                        });
+                        self.nodes[child].start += shared;
+                        self.nodes[node].children[slot] = middle;
+                        node = middle;
+                    } else {
+                        node = child;
+                    }
+                    depth += shared;
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn id(&self, index: usize) -> &'a str {
+        &self.ids[index]
+    }
+
+    // Every id starting with prefix
+    pub fn with_prefix(&self, prefix: &str) -> Vec<usize> {
+        self.find(prefix, true)
+    }
+
+    // Every id matching pattern as a whole
+    pub fn matching(&self, pattern: &str) -> Vec<usize> {
+        self.find(pattern, false)
+    }
+
+    fn find(&self, query: &str, prefix: bool) -> Vec<usize> {
+        let query = unicode::normalize(query);
+        let mut pattern: Vec<Query> = Vec::new();
+        for c in unicode::characters(&query) {
+            pattern.push(if c == WILDCARD {
+                Query::Any
+            } else {
+                match self.characters.number(c) {
+                    Some(number) => Query::Is(number),
+                    None => return Vec::new(),
+                }
+            });
+        }
+
+        let mut found: Vec<usize> = Vec::new();
+        // (node, how much of the pattern the path to it matched)
+        let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+        while let Some((node, depth)) = stack.pop() {
+            if depth == pattern.len() {
+                if prefix {
+                    self.collect(node, &mut found);
+                } else {
+                    found.extend(&self.nodes[node].ends);
+                }
+                continue;
+            }
+            let children = &self.nodes[node].children;
+            let candidates = match pattern[depth] {
+                Query::Any => children.as_slice(),
+                Query::Is(c) => match children.binary_search_by_key(&c, |&child| self.label(child)[0]) {
+                    Ok(slot) => &children[slot..=slot],
+                    Err(_) => &[],
+                },
+            };
+            for &child in candidates {
+                let label = self.label(child);
+                let rest = &pattern[depth..];
+                let compared = label.len().min(rest.len());
+                let matches = label[..compared]
+                    .iter()
+                    .zip(&rest[..compared])
+                    .all(|(c, q)| *q == Query::Any || *q == Query::Is(*c));
+                if !matches {
+                    continue;
+                }
+                if compared == label.len() {
+                    stack.push((child, depth + compared));
+                } else if prefix {
+                    // The prefix ends part way along this edge
+                    self.collect(child, &mut found);
+                }
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+
+    // Every id at or below node
+    fn collect(&self, node: usize, found: &mut Vec<usize>) {
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            found.extend(&self.nodes[node].ends);
+            stack.extend(&self.nodes[node].children);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Catalogue, WILDCARD};
+    use aoc_common::random::Rng;
+    use unicode;
+
+    // Whether the query's characters match the start of the id (all of it, unless prefix)
+    fn matches(id: &str, query: &str, prefix: bool) -> bool {
+        let (id, query) = (unicode::characters(id), unicode::characters(query));
+        (if prefix { query.len() <= id.len() } else { query.len() == id.len() })
+            && query.iter().zip(&id).all(|(q, c)| *q == WILDCARD || q == c)
+    }
+
+    fn filter(ids: &[String], query: &str, prefix: bool) -> Vec<usize> {
+        (0..ids.len()).filter(|&i| matches(&ids[i], query, prefix)).collect()
+    }
+
+    // Ids of different lengths (some the prefix of others, some repeated), and queries that also use
+    // wildcards and a character no id has
+    #[test]
+    fn queries_match_filter() {
+        let mut rng = Rng::new(13);
+        let ids: Vec<String> = (0..200)
+            .map(|_| (0..rng.range(0, 5)).map(|_| *rng.pick(&["a", "b", "c", "\u{e9}"])).collect())
+            .collect();
+        let catalogue = Catalogue::new(&ids);
+        for _ in 0..500 {
+            let query: String = (0..rng.range(0, 5)).map(|_| *rng.pick(&["a", "b", "c", "\u{e9}", "?", "?", "z"])).collect();
+            assert_eq!(catalogue.with_prefix(&query), filter(&ids, &query, true), "prefix {}", query);
+            assert_eq!(catalogue.matching(&query), filter(&ids, &query, false), "pattern {}", query);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use aoc_common::{Answer, Error, Result};
+use std::vec::Vec;
+
+use unicode::{self, Interner};
+use {common_characters, parse_boxids};
+
+/*
+    Approach 1 from the top of lib.rs (originally main_part2_failedattempt.rs)
+
+    Sum up the character values of every word (see char_sums), sort by that sum, and only compare
+    neighbours whose sums differ by exactly 1.
+    This is NOT correct: words differing in one position can have sums differing by anything
+    (abc vs abz), and neighbours in sorted order are not the only candidates.
+    Kept so that it can be benchmarked against the brute force approach (and see prefilter for
+    how much it misses, and a sound way of choosing what to compare).
+*/
+pub fn find_common_characters(boxids: &[String]) -> Option<String> {
+    let mut map: Vec<(&str, u64)> = boxids.iter().map(|line| line.as_str()).zip(char_sums(boxids)).collect();
+
+    // Sort the map
+    map.sort_by_key(|(_, value)| *value);
+
+    let mut prev_line = "";
+    let mut prev_value: Option<u64> = None;
+    for (line, value) in &map {
+        // We want to make sure there is only a delta of 1 between previous and current values
+        // This must mean we only diff by 1 character value
+        if prev_value.map(|prev| value - prev) == Some(1) && unicode::length(prev_line) == unicode::length(line) {
+            let non_repeated = common_characters(prev_line, line);
+
+            // Only differs by 1 char
+            if unicode::length(&non_repeated) + 1 == unicode::length(line) {
+                return Some(non_repeated);
+            }
+        }
+
+        prev_line = line;
+        prev_value = Some(*value);
+    }
+    None
+}
+
+// The sum of the values of each id's characters. A character that is one code point is worth that code
+// point, and any other (a letter with a combining accent, a flag) a number of its own above every code
+// point, so that changing one character changes the sum however many code points it has
+pub fn char_sums<S: AsRef<str>>(boxids: &[S]) -> Vec<u64> {
+    let mut interner = Interner::new();
+    boxids
+        .iter()
+        .map(|id| {
+            unicode::characters(id.as_ref())
+                .into_iter()
+                .map(|c| {
+                    let mut code_points = c.chars();
+                    match (code_points.next(), code_points.next()) {
+                        (Some(only), None) => u64::from(u32::from(only)),
+                        _ => u64::from(char::MAX) + 1 + u64::from(interner.intern(c)[0]),
+                    }
+                })
+                .sum()
+        })
+        .collect()
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    find_common_characters(&parse_boxids(input)?)
+        .map(Answer::from)
+        .ok_or_else(|| Error::NoAnswer("no neighbouring char sums differ by exactly one".to_string()))
+}
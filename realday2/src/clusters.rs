@@ -0,0 +1,128 @@
+use masked::{self, NearPair};
+
+/*
+    Families of near-duplicate box ids: the connected components of the graph whose edges are the
+    pairs of ids differing in exactly one position (from masked)
+
+    Ids in a family need not be one apart from each other (abc - abd - abe - xbe), so a family's
+    positions are every position its members do not all agree on, which is exactly the positions of
+    its edges: walking along edges from one member to another only ever changes those positions
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    // Indexes of the member ids, in input order
+    pub ids: Vec<usize>,
+    // Positions (in characters) the members differ at, in order
+    pub positions: Vec<usize>,
+}
+
+// Every group of two or more ids, ordered by their first member
+pub fn groups(boxids: &[String]) -> Vec<Group> {
+    groups_of(boxids.len(), &masked::distance_one_pairs(boxids))
+}
+
+pub fn groups_of(count: usize, pairs: &[NearPair]) -> Vec<Group> {
+    // Union-find over the ids, always keeping the smaller index as the root
+    let mut parent: Vec<usize> = (0..count).collect();
+    for pair in pairs {
+        let (a, b) = (root(&mut parent, pair.first), root(&mut parent, pair.second));
+        if a < b {
+            parent[b] = a;
+        } else {
+            parent[a] = b;
+        }
+    }
+
+    // Roots are the first member of their group
+    let mut group_of: Vec<Option<usize>> = vec![None; count];
+    let mut groups: Vec<Group> = Vec::new();
+    for id in 0..count {
+        let r = root(&mut parent, id);
+        if r == id {
+            continue;
+        }
+        let index = match group_of[r] {
+            Some(index) => index,
+            None => {
+                group_of[r] = Some(groups.len());
+                groups.push(Group { ids: vec![r], positions: Vec::new() });
+                groups.len() - 1
+            }
+        };
+        groups[index].ids.push(id);
+    }
+
+    for pair in pairs {
+        let r = root(&mut parent, pair.first);
+        if let Some(index) = group_of[r] {
+            groups[index].positions.push(pair.position);
+        }
+    }
+    for group in &mut groups {
+        group.positions.sort();
+        group.positions.dedup();
+    }
+    groups.sort_by_key(|group| group.ids[0]);
+    groups
+}
+
+fn root(parent: &mut [usize], mut id: usize) -> usize {
+    while parent[id] != id {
+        parent[id] = parent[parent[id]];
+        id = parent[id];
+    }
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{groups, Group};
+    use aoc_common::random::Rng;
+    use hamming_distance_same_len;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn chain_is_one_group() {
+        // abc and xbd are two apart, but joined through abd
+        assert_eq!(
+            groups(&ids(&["xbd", "qqq", "abc", "abd"])),
+            vec![Group { ids: vec![0, 2, 3], positions: vec![0, 2] }]
+        );
+        assert_eq!(groups(&ids(&["abc", "xyz"])), vec![]);
+    }
+
+    // Which ids can be reached from which by steps of one position, found by a search from every id
+    #[test]
+    fn members_match_search() {
+        let mut rng = Rng::new(9);
+        let boxids: Vec<String> = (0..80).map(|_| (0..5).map(|_| *rng.pick(&["a", "b", "c", "d"])).collect()).collect();
+        let mut expected: Vec<Vec<usize>> = Vec::new();
+        let mut seen = vec![false; boxids.len()];
+        for start in 0..boxids.len() {
+            if seen[start] {
+                continue;
+            }
+            let (mut members, mut stack) = (vec![start], vec![start]);
+            seen[start] = true;
+            while let Some(id) = stack.pop() {
+                for other in 0..boxids.len() {
+                    if !seen[other] && hamming_distance_same_len(&boxids[id], &boxids[other]) == 1 {
+                        seen[other] = true;
+                        members.push(other);
+                        stack.push(other);
+                    }
+                }
+            }
+            members.sort();
+            if members.len() > 1 {
+                expected.push(members);
+            }
+        }
+        let found: Vec<Vec<usize>> = groups(&boxids).into_iter().map(|group| group.ids).collect();
+        assert!(found.len() > 1);
+        assert_eq!(found, expected);
+    }
+}
@@ -0,0 +1,359 @@
+use aoc_common::{Answer, Error, Result};
+use std::collections::HashMap;
+use std::mem;
+
+use bktree::{self, BkTree};
+use unicode::{self, Interner};
+use parse_any_boxids;
+
+/*
+    Edit distances, for box ids that are not all the same length
+
+    Levenshtein counts the single character insertions, deletions and substitutions needed to turn
+    one id into the other. Damerau also allows swapping two characters, with any number of insertions
+    and deletions between them (the unrestricted version, so that it is still a metric and can be used
+    in the BK-tree; "optimal string alignment" is not).
+
+    The common characters are the ones the alignment matches up. Several alignments can share the
+    minimum distance (ab vs ba: substitute both, or delete a and insert it again), so of those the one
+    matching the most characters is used, and after that diagonal steps are preferred over deletions
+    over insertions, so the answer does not depend on anything but the two ids
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance {
+    Hamming,
+    Levenshtein,
+    Damerau,
+}
+
+pub const DISTANCE_NAMES: &[&str] = &["hamming", "levenshtein", "damerau"];
+
+impl Distance {
+    pub fn from_name(name: &str) -> Option<Distance> {
+        match name {
+            "hamming" => Some(Distance::Hamming),
+            "levenshtein" => Some(Distance::Levenshtein),
+            "damerau" => Some(Distance::Damerau),
+            _ => None,
+        }
+    }
+
+    pub fn metric(self) -> bktree::Metric {
+        match self {
+            Distance::Hamming => bktree::hamming,
+            Distance::Levenshtein => levenshtein_within,
+            Distance::Damerau => damerau_within,
+        }
+    }
+}
+
+// The edit distance between two ids and the characters an optimal alignment keeps, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub distance: u32,
+    pub common: String,
+}
+
+// How the alignment got to a cell: a diagonal step (a match if the characters are equal, a
+// substitution if not), deleting from x, inserting from y, or swapping x[k] and x[i] to y[l] and y[j]
+// (1-based, deleting everything in x and inserting everything in y that lies between them)
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Start,
+    Diagonal,
+    Delete,
+    Insert,
+    Transpose(usize, usize),
+}
+
+pub fn levenshtein(x: &str, y: &str) -> u32 {
+    levenshtein_within(x, y, u32::MAX)
+}
+
+pub fn damerau(x: &str, y: &str) -> u32 {
+    damerau_within(x, y, u32::MAX)
+}
+
+/*
+    The distances alone, without the alignment, for comparing many ids (the BK-tree): the exact distance
+    when it is at most limit, otherwise something above limit (see bktree::Metric)
+
+    Both stop as soon as a whole row is above limit: an edit distance between prefixes changes by at most
+    one from one row to the next, so the rows after it (and the last cell) can only be above limit too.
+    Levenshtein only needs the row above, but a transposition can reach back to any earlier row, so
+    Damerau keeps every row (the three-row version is "optimal string alignment", which is not a metric)
+*/
+pub fn levenshtein_within(x: &str, y: &str, limit: u32) -> u32 {
+    let (x, y) = symbols(x, y);
+    let (n, m) = (x.len(), y.len());
+    if (n as u64).abs_diff(m as u64) > u64::from(limit) {
+        return limit.saturating_add(1);
+    }
+    let mut previous: Vec<u32> = (0..=m as u32).collect();
+    let mut current: Vec<u32> = vec![0; m + 1];
+    for i in 1..=n {
+        current[0] = i as u32;
+        let mut row_min = current[0];
+        for j in 1..=m {
+            let diagonal = previous[j - 1] + if x[i - 1] == y[j - 1] { 0 } else { 1 };
+            current[j] = diagonal.min(previous[j] + 1).min(current[j - 1] + 1);
+            row_min = row_min.min(current[j]);
+        }
+        if row_min > limit {
+            return limit.saturating_add(1);
+        }
+        mem::swap(&mut previous, &mut current);
+    }
+    previous[m]
+}
+
+pub fn damerau_within(x: &str, y: &str, limit: u32) -> u32 {
+    let (x, y) = symbols(x, y);
+    let (n, m) = (x.len(), y.len());
+    if (n as u64).abs_diff(m as u64) > u64::from(limit) {
+        return limit.saturating_add(1);
+    }
+    let width = m + 1;
+    let mut cells: Vec<u32> = vec![0; (n + 1) * width];
+    for (j, cell) in cells[..width].iter_mut().enumerate() {
+        *cell = j as u32;
+    }
+    // Last row (1-based) each character of x was seen in, as in align
+    let alphabet = x.iter().chain(&y).max().map_or(0, |&c| c as usize + 1);
+    let mut last_row: Vec<usize> = vec![0; alphabet];
+    for i in 1..=n {
+        cells[i * width] = i as u32;
+        let mut row_min = i as u32;
+        let mut last_column = 0;
+        for j in 1..=m {
+            let matched = x[i - 1] == y[j - 1];
+            let diagonal = cells[(i - 1) * width + j - 1] + if matched { 0 } else { 1 };
+            let mut best = diagonal.min(cells[(i - 1) * width + j] + 1).min(cells[i * width + j - 1] + 1);
+            let (k, l) = (last_row[y[j - 1] as usize], last_column);
+            if k > 0 && l > 0 {
+                best = best.min(cells[(k - 1) * width + l - 1] + (i - k - 1) as u32 + 1 + (j - l - 1) as u32);
+            }
+            if matched {
+                last_column = j;
+            }
+            cells[i * width + j] = best;
+            row_min = row_min.min(best);
+        }
+        if row_min > limit {
+            return limit.saturating_add(1);
+        }
+        last_row[x[i - 1] as usize] = i;
+    }
+    cells[n * width + m]
+}
+
+// Both ids as one number per character: the bytes when both are ASCII, otherwise numbers given to
+// their characters (see unicode)
+fn symbols(x: &str, y: &str) -> (Vec<u32>, Vec<u32>) {
+    if x.is_ascii() && y.is_ascii() {
+        return (x.bytes().map(u32::from).collect(), y.bytes().map(u32::from).collect());
+    }
+    let mut interner = Interner::new();
+    (interner.intern(x), interner.intern(y))
+}
+
+pub fn levenshtein_alignment(x: &str, y: &str) -> Alignment {
+    align(x, y, false)
+}
+
+pub fn damerau_alignment(x: &str, y: &str) -> Alignment {
+    align(x, y, true)
+}
+
+/*
+    Wagner-Fischer, plus Lowrance-Wagner's transpositions when transpose is set
+
+    cells[i][j] is the best (distance, matched characters) for the first i characters of x against the
+    first j of y. For a transposition ending at x[i], y[j], k is the last row before i whose character is
+    y[j] and l the last column before j whose character is x[i]: only the closest pair can be optimal
+*/
+fn align(x: &str, y: &str, transpose: bool) -> Alignment {
+    let (x, y) = (unicode::characters(x), unicode::characters(y));
+    let (n, m) = (x.len(), y.len());
+    let width = m + 1;
+    let mut cells: Vec<(u32, u32)> = vec![(0, 0); (n + 1) * width];
+    let mut steps: Vec<Step> = vec![Step::Start; (n + 1) * width];
+    for i in 1..=n {
+        cells[i * width] = (i as u32, 0);
+        steps[i * width] = Step::Delete;
+    }
+    for j in 1..=m {
+        cells[j] = (j as u32, 0);
+        steps[j] = Step::Insert;
+    }
+
+    // Lower distance wins, then more matched characters, then the earlier candidate
+    let better = |a: (u32, u32), b: (u32, u32)| a.0 < b.0 || (a.0 == b.0 && a.1 > b.1);
+
+    // Last row (1-based) each character of x was seen in
+    let mut last_row: HashMap<&str, usize> = HashMap::new();
+    for i in 1..=n {
+        // Last column (1-based) in this row where y matched x[i - 1]
+        let mut last_column = 0;
+        for j in 1..=m {
+            let matched = x[i - 1] == y[j - 1];
+            let diagonal = cells[(i - 1) * width + j - 1];
+            let mut best = if matched { (diagonal.0, diagonal.1 + 1) } else { (diagonal.0 + 1, diagonal.1) };
+            let mut step = Step::Diagonal;
+
+            let up = cells[(i - 1) * width + j];
+            if better((up.0 + 1, up.1), best) {
+                best = (up.0 + 1, up.1);
+                step = Step::Delete;
+            }
+            let left = cells[i * width + j - 1];
+            if better((left.0 + 1, left.1), best) {
+                best = (left.0 + 1, left.1);
+                step = Step::Insert;
+            }
+            if transpose {
+                let (k, l) = (last_row.get(&y[j - 1]).cloned().unwrap_or(0), last_column);
+                if k > 0 && l > 0 {
+                    let before = cells[(k - 1) * width + l - 1];
+                    let swapped = (before.0 + (i - k - 1) as u32 + 1 + (j - l - 1) as u32, before.1);
+                    if better(swapped, best) {
+                        best = swapped;
+                        step = Step::Transpose(k, l);
+                    }
+                }
+            }
+            if matched {
+                last_column = j;
+            }
+            cells[i * width + j] = best;
+            steps[i * width + j] = step;
+        }
+        last_row.insert(x[i - 1], i);
+    }
+
+    // Walk back from the end collecting the matched characters
+    let mut common: Vec<&str> = Vec::new();
+    let (mut i, mut j) = (n, m);
+    loop {
+        match steps[i * width + j] {
+            Step::Start => break,
+            Step::Diagonal => {
+                if x[i - 1] == y[j - 1] {
+                    common.push(x[i - 1]);
+                }
+                i -= 1;
+                j -= 1;
+            }
+            Step::Delete => i -= 1,
+            Step::Insert => j -= 1,
+            Step::Transpose(k, l) => {
+                i = k - 1;
+                j = l - 1;
+            }
+        }
+    }
+    common.reverse();
+    Alignment {
+        distance: cells[n * width + m].0,
+        common: common.concat(),
+    }
+}
+
+// The first pair of ids (by first and then second index) at distance exactly one
+pub fn first_pair(boxids: &[String], distance: Distance) -> Option<(usize, usize)> {
+    let tree = BkTree::with_metric(boxids, distance.metric());
+    (0..boxids.len()).find_map(|first| {
+        tree.within(&boxids[first], 1)
+            .into_iter()
+            .filter(|m| m.distance == 1 && m.index > first)
+            .map(|m| m.index)
+            .min()
+            .map(|second| (first, second))
+    })
+}
+
+fn common_characters_of_first_pair(input: &str, distance: Distance) -> Result<Answer> {
+    let boxids = parse_any_boxids(input)?;
+    let (first, second) = first_pair(&boxids, distance)
+        .ok_or_else(|| Error::NoAnswer("no two box ids are one edit apart".to_string()))?;
+    let (x, y) = (&boxids[first], &boxids[second]);
+    let alignment = match distance {
+        Distance::Damerau => damerau_alignment(x, y),
+        _ => levenshtein_alignment(x, y),
+    };
+    Ok(alignment.common.into())
+}
+
+pub fn part2_levenshtein(input: &str) -> Result<Answer> {
+    common_characters_of_first_pair(input, Distance::Levenshtein)
+}
+
+pub fn part2_damerau(input: &str) -> Result<Answer> {
+    common_characters_of_first_pair(input, Distance::Damerau)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{align, damerau_alignment, damerau_within, levenshtein_alignment, levenshtein_within, Alignment};
+    use aoc_common::random::Rng;
+
+    // Short ids over a few characters (one of them several code points), so that edits overlap a lot
+    fn random_id(rng: &mut Rng) -> String {
+        let len = rng.below(7);
+        (0..len).map(|_| *rng.pick(&["a", "b", "c", "e\u{301}"])).collect()
+    }
+
+    #[test]
+    fn distances_match_align() {
+        let mut rng = Rng::new(1);
+        for _ in 0..2000 {
+            let (x, y) = (random_id(&mut rng), random_id(&mut rng));
+            for &transpose in &[false, true] {
+                let within = if transpose { damerau_within } else { levenshtein_within };
+                let distance = align(&x, &y, transpose).distance;
+                assert_eq!(within(&x, &y, u32::MAX), distance, "{} {} {}", x, y, transpose);
+                // Below the limit exactly, above it anything above it
+                for limit in 0..=distance + 1 {
+                    let bounded = within(&x, &y, limit);
+                    if distance <= limit {
+                        assert_eq!(bounded, distance, "{} {} {} {}", x, y, transpose, limit);
+                    } else {
+                        assert!(bounded > limit, "{} {} {} {}", x, y, transpose, limit);
+                    }
+                }
+            }
+        }
+    }
+
+    fn alignment(distance: u32, common: &str) -> Alignment {
+        Alignment { distance, common: common.to_string() }
+    }
+
+    #[test]
+    fn common_after_insertions() {
+        for &(x, y, distance, common) in &[
+            ("abcd", "abxcd", 1, "abcd"),
+            ("abcd", "xabcdx", 2, "abcd"),
+            ("", "abc", 3, ""),
+            // A combining mark makes i a different character, rather than adding one
+            ("naive", "nai\u{308}ve", 1, "nave"),
+            ("\u{1f1eb}\u{1f1f7}b", "a\u{1f1eb}\u{1f1f7}b", 1, "\u{1f1eb}\u{1f1f7}b"),
+        ] {
+            assert_eq!(levenshtein_alignment(x, y), alignment(distance, common), "{} {}", x, y);
+            assert_eq!(levenshtein_alignment(y, x), alignment(distance, common), "{} {}", y, x);
+            assert_eq!(damerau_alignment(x, y), alignment(distance, common), "{} {}", x, y);
+        }
+    }
+
+    #[test]
+    fn common_after_transpositions() {
+        // A swap keeps neither of the swapped characters
+        assert_eq!(damerau_alignment("abcd", "acbd"), alignment(1, "ad"));
+        assert_eq!(damerau_alignment("ab", "ba"), alignment(1, ""));
+        // Of the alignments at the same distance, the one keeping the most characters
+        assert_eq!(damerau_alignment("axbc", "bayc"), alignment(3, "ac"));
+        // Levenshtein has to delete and insert again instead, keeping one of them
+        assert_eq!(levenshtein_alignment("abcd", "acbd"), alignment(2, "abd"));
+        assert_eq!(levenshtein_alignment("ab", "ba"), alignment(2, "a"));
+    }
+}
@@ -0,0 +1,479 @@
+use aoc_common::Result;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{self, AtomicUsize};
+
+use masked::NearPair;
+use unicode;
+
+/*
+    Approach 2 (masked positions, see masked) for id files that do not fit in memory
+
+    Every id is streamed in once and turned into one record per position: the position, the characters
+    before it, the characters after it, and the character itself. Two ids differ only at position p iff
+    they have records at p with the same before and after parts and different characters. So sorting all
+    the records brings every candidate pair next to each other, and no id has to be kept around.
+
+    Records pile up in memory until they reach the budget, then get sorted and written out to a run file
+    in a temporary directory (under TMPDIR, removed again afterwards). At the end the runs are merged,
+    at most as many at a time as half the budget has read buffers for (and never more than MAX_FAN_IN,
+    to stay well inside the usual limit on open files), and each group of records with the same
+    position, before and after parts gives the pairs. Both ids are rebuilt from their records and checked
+    before a pair is reported.
+
+    Within a group the records are sorted by character, so each record only needs comparing with the
+    earlier records of other characters: copies of one id never get compared with each other. A group
+    that outgrows the other half of the budget (many copies of the same few ids) is written out to a
+    file of its own and paired up from there.
+
+    Unlike masked, pairs come out ordered by position and then by the rest of the id, not by line
+*/
+pub const DEFAULT_BUDGET: usize = 64 << 20;
+
+// Rough cost in memory of one record besides its text: three Strings, the numbers, and its slot in the Vec
+const RECORD_OVERHEAD: usize = 3 * 24 + 16 + 48;
+// Read (and write) buffer for each run file being merged
+const RUN_BUFFER: usize = 64 << 10;
+// Most run files open at once
+const MAX_FAN_IN: usize = 64;
+
+static SPILLS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Record {
+    position: u32,
+    before: String,
+    after: String,
+    character: String,
+    line: u64,
+}
+
+impl Record {
+    fn id(&self) -> String {
+        [self.before.as_str(), self.character.as_str(), self.after.as_str()].concat()
+    }
+
+    fn size(&self) -> usize {
+        self.before.len() + self.after.len() + self.character.len() + RECORD_OVERHEAD
+    }
+
+    fn write<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        out.write_all(&self.position.to_le_bytes())?;
+        out.write_all(&self.line.to_le_bytes())?;
+        for text in &[&self.before, &self.after, &self.character] {
+            out.write_all(&(text.len() as u32).to_le_bytes())?;
+            out.write_all(text.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    // None at the end of the run
+    fn read<R: Read>(input: &mut R) -> io::Result<Option<Record>> {
+        let mut position = [0u8; 4];
+        match input.read_exact(&mut position) {
+            Ok(()) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let mut line = [0u8; 8];
+        input.read_exact(&mut line)?;
+        let (before, after, character) = (read_text(input)?, read_text(input)?, read_text(input)?);
+        Ok(Some(Record {
+            position: u32::from_le_bytes(position),
+            before,
+            after,
+            line: u64::from_le_bytes(line),
+            character,
+        }))
+    }
+
+    fn same_group(&self, other: &Record) -> bool {
+        self.position == other.position && self.before == other.before && self.after == other.after
+    }
+}
+
+fn read_text<R: Read>(input: &mut R) -> io::Result<String> {
+    let mut len = [0u8; 4];
+    input.read_exact(&mut len)?;
+    let mut bytes = vec![0u8; u32::from_le_bytes(len) as usize];
+    input.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+// A sorted run file (or a group file), read back one record at a time
+struct Run {
+    input: BufReader<File>,
+}
+
+impl Run {
+    fn open(path: &PathBuf) -> io::Result<Run> {
+        Ok(Run {
+            input: BufReader::with_capacity(RUN_BUFFER, File::open(path)?),
+        })
+    }
+}
+
+impl Iterator for Run {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<io::Result<Record>> {
+        Record::read(&mut self.input).transpose()
+    }
+}
+
+// How a search went, for reporting
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub ids: u64,
+    pub records: u64,
+    pub runs: usize,
+    pub pairs: u64,
+}
+
+pub struct Finder {
+    budget: usize,
+    buffer: Vec<Record>,
+    buffered: usize,
+    dir: Option<PathBuf>,
+    runs: Vec<PathBuf>,
+    stats: Stats,
+}
+
+impl Finder {
+    // budget is in bytes, and only bounds the records and buffers (not, say, one absurdly long id)
+    pub fn new(budget: usize) -> Finder {
+        Finder {
+            budget,
+            buffer: Vec::new(),
+            buffered: 0,
+            dir: None,
+            runs: Vec::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    // Every line of input is one id, numbered on from the ids added before
+    pub fn add<R: BufRead>(&mut self, input: R) -> Result<()> {
+        for line in input.lines() {
+            self.add_id(&line?)?;
+        }
+        Ok(())
+    }
+
+    // Ids added so far
+    pub fn ids(&self) -> usize {
+        self.stats.ids as usize
+    }
+
+    pub fn add_id(&mut self, id: &str) -> Result<()> {
+        let id = unicode::normalize(id);
+        let characters = unicode::characters(&id);
+        let line = self.stats.ids;
+        self.stats.ids += 1;
+        for (position, character) in characters.iter().enumerate() {
+            let record = Record {
+                position: position as u32,
+                before: characters[..position].concat(),
+                after: characters[position + 1..].concat(),
+                line,
+                character: character.to_string(),
+            };
+            self.buffered += record.size();
+            self.buffer.push(record);
+            self.stats.records += 1;
+        }
+        if self.buffered > self.budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    // Sorts what is in memory and writes it out as a new run
+    fn spill(&mut self) -> Result<()> {
+        self.buffer.sort_unstable();
+        let path = self.run_path()?;
+        let mut out = BufWriter::with_capacity(RUN_BUFFER, File::create(&path)?);
+        for record in self.buffer.drain(..) {
+            record.write(&mut out)?;
+        }
+        out.flush()?;
+        self.buffered = 0;
+        self.runs.push(path);
+        self.stats.runs += 1;
+        Ok(())
+    }
+
+    fn run_path(&mut self) -> Result<PathBuf> {
+        let name = format!("run-{}", self.stats.runs);
+        self.temp_path(&name)
+    }
+
+    fn temp_path(&mut self, name: &str) -> Result<PathBuf> {
+        if self.dir.is_none() {
+            let spill = SPILLS.fetch_add(1, atomic::Ordering::SeqCst);
+            let dir = env::temp_dir().join(format!("realday2-{}-{}", process::id(), spill));
+            fs::create_dir_all(&dir)?;
+            self.dir = Some(dir);
+        }
+        Ok(self.dir.as_ref().unwrap().join(name))
+    }
+
+    // Reports every pair of ids (numbered by line from 0) differing at exactly one position
+    pub fn finish<F: FnMut(NearPair, &str, &str)>(mut self, mut report: F) -> Result<Stats> {
+        if self.runs.is_empty() {
+            // Everything fit, so there is nothing to merge
+            let mut buffer = mem::take(&mut self.buffer);
+            buffer.sort_unstable();
+            self.stats.pairs = self.report_pairs(buffer.into_iter().map(Ok), &mut report)?;
+            return Ok(self.stats);
+        }
+        if !self.buffer.is_empty() {
+            self.spill()?;
+        }
+
+        // Merge runs into bigger runs until the rest can all be merged at once
+        let fan_in = (self.budget / 2 / RUN_BUFFER).clamp(2, MAX_FAN_IN);
+        while self.runs.len() > fan_in {
+            let batch: Vec<PathBuf> = self.runs.drain(..fan_in).collect();
+            let path = self.run_path()?;
+            let mut out = BufWriter::with_capacity(RUN_BUFFER, File::create(&path)?);
+            for record in merge(&batch)? {
+                record?.write(&mut out)?;
+            }
+            out.flush()?;
+            for run in &batch {
+                fs::remove_file(run)?;
+            }
+            self.runs.push(path);
+            self.stats.runs += 1;
+        }
+        let runs = merge(&self.runs)?;
+        self.stats.pairs = self.report_pairs(runs, &mut report)?;
+        Ok(self.stats)
+    }
+
+    // Goes through sorted records a group (same position, before and after parts) at a time
+    fn report_pairs<I, F>(&mut self, records: I, report: &mut F) -> Result<u64>
+    where
+        I: Iterator<Item = io::Result<Record>>,
+        F: FnMut(NearPair, &str, &str),
+    {
+        let mut pairs = 0;
+        let mut group: Vec<Record> = Vec::new();
+        let mut group_size = 0;
+        // Where the group goes once it no longer fits in memory
+        let mut group_file: Option<(PathBuf, BufWriter<File>)> = None;
+        let mut records = records.peekable();
+        while let Some(record) = records.next() {
+            let record = record?;
+            let ends_group = match records.peek() {
+                Some(Ok(next)) => !record.same_group(next),
+                _ => true,
+            };
+            group_size += record.size();
+            group.push(record);
+            if group_size > self.budget / 2 {
+                if group_file.is_none() {
+                    let path = self.temp_path("group")?;
+                    let out = BufWriter::with_capacity(RUN_BUFFER, File::create(&path)?);
+                    group_file = Some((path, out));
+                }
+                let (_, out) = group_file.as_mut().unwrap();
+                for record in group.drain(..) {
+                    record.write(out)?;
+                }
+                group_size = 0;
+            }
+            if !ends_group {
+                continue;
+            }
+            match group_file.take() {
+                None => pairs += group_pairs(&group, report),
+                Some((path, mut out)) => {
+                    for record in group.drain(..) {
+                        record.write(&mut out)?;
+                    }
+                    out.flush()?;
+                    drop(out);
+                    pairs += file_group_pairs(&path, report)?;
+                    fs::remove_file(&path)?;
+                }
+            }
+            group.clear();
+            group_size = 0;
+        }
+        Ok(pairs)
+    }
+}
+
+impl Drop for Finder {
+    fn drop(&mut self) {
+        if let Some(ref dir) = self.dir {
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+}
+
+// The records of all the runs, in order
+fn merge(paths: &[PathBuf]) -> Result<impl Iterator<Item = io::Result<Record>>> {
+    let mut runs: Vec<Run> = Vec::with_capacity(paths.len());
+    for path in paths {
+        runs.push(Run::open(path)?);
+    }
+    let mut heads: BinaryHeap<Reverse<(Record, usize)>> = BinaryHeap::with_capacity(runs.len());
+    for (i, run) in runs.iter_mut().enumerate() {
+        if let Some(record) = run.next() {
+            heads.push(Reverse((record?, i)));
+        }
+    }
+    Ok(Merge { runs, heads })
+}
+
+struct Merge {
+    runs: Vec<Run>,
+    heads: BinaryHeap<Reverse<(Record, usize)>>,
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<io::Result<Record>> {
+        let Reverse((record, i)) = self.heads.pop()?;
+        match self.runs[i].next() {
+            Some(Ok(next)) => self.heads.push(Reverse((next, i))),
+            Some(Err(e)) => return Some(Err(e)),
+            None => {}
+        }
+        Some(Ok(record))
+    }
+}
+
+// Pairs up a group held in memory: sorted by character, so every record is compared with the records of
+// the other characters before it
+fn group_pairs<F: FnMut(NearPair, &str, &str)>(group: &[Record], report: &mut F) -> u64 {
+    let mut pairs = 0;
+    let mut start = 0;
+    for (i, second) in group.iter().enumerate() {
+        if second.character != group[start].character {
+            start = i;
+        }
+        for first in &group[..start] {
+            pairs += report_pair(first, second, report);
+        }
+    }
+    pairs
+}
+
+// The same for a group written out to a file: the file is read again from the start for each record,
+// up to where that record's character begins
+fn file_group_pairs<F: FnMut(NearPair, &str, &str)>(path: &PathBuf, report: &mut F) -> Result<u64> {
+    let mut pairs = 0;
+    let (mut start, mut start_character) = (0, None);
+    for (i, second) in Run::open(path)?.enumerate() {
+        let second = second?;
+        if start_character.as_ref() != Some(&second.character) {
+            start = i;
+            start_character = Some(second.character.clone());
+        }
+        for first in Run::open(path)?.take(start) {
+            pairs += report_pair(&first?, &second, report);
+        }
+    }
+    Ok(pairs)
+}
+
+// Reports two records of a group if their ids really do differ only there, with the lower line first
+fn report_pair<F: FnMut(NearPair, &str, &str)>(a: &Record, b: &Record, report: &mut F) -> u64 {
+    let (first, second) = if a.line < b.line { (a, b) } else { (b, a) };
+    let (x, y) = (first.id(), second.id());
+    let position = first.position as usize;
+    if !differ_only_at(&x, &y, position) {
+        return 0;
+    }
+    let pair = NearPair {
+        first: first.line as usize,
+        second: second.line as usize,
+        position,
+    };
+    report(pair, &x, &y);
+    1
+}
+
+// Rebuilding an id from its parts gives back the same text, but not always the same characters: a
+// combining mark after the gap can join a different character. So check the ids as they are
+fn differ_only_at(x: &str, y: &str, position: usize) -> bool {
+    let (x, y) = (unicode::characters(x), unicode::characters(y));
+    x.len() == y.len()
+        && position < x.len()
+        && x[position] != y[position]
+        && x[..position] == y[..position]
+        && x[position + 1..] == y[position + 1..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Finder, DEFAULT_BUDGET};
+    use aoc_common::random::{Options, Rng};
+    use generate;
+    use masked::{self, NearPair};
+
+    // With a budget of 1 every id is spilled to a run of its own, every merge is of two runs, and every
+    // group goes through a group file. With the default budget everything stays in memory
+    fn external_pairs(ids: &[String], budget: usize) -> Vec<(usize, usize, usize)> {
+        let mut finder = Finder::new(budget);
+        for id in ids {
+            finder.add_id(id).unwrap();
+        }
+        let mut found: Vec<(usize, usize, usize)> = Vec::new();
+        let stats = finder
+            .finish(|pair, first, second| {
+                assert_eq!((first, second), (ids[pair.first].as_str(), ids[pair.second].as_str()));
+                found.push((pair.first, pair.second, pair.position));
+            })
+            .unwrap();
+        assert_eq!(stats.pairs as usize, found.len());
+        if budget == 1 {
+            assert!(stats.runs > ids.len());
+        } else {
+            assert_eq!(stats.runs, 0);
+        }
+        found.sort();
+        found
+    }
+
+    fn masked_pairs(ids: &[String]) -> Vec<(usize, usize, usize)> {
+        let mut pairs: Vec<(usize, usize, usize)> = masked::distance_one_pairs(ids)
+            .into_iter()
+            .map(|NearPair { first, second, position }| (first, second, position))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn generated_ids_match_masked() {
+        let options = Options { size: Some(200), ..Options::default() };
+        let ids: Vec<String> = generate::generate(&mut Rng::new(7), &options).lines().map(String::from).collect();
+        let pairs = external_pairs(&ids, 1);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs, masked_pairs(&ids));
+        assert_eq!(external_pairs(&ids, DEFAULT_BUDGET), pairs);
+    }
+
+    // Few characters and short ids: lots of pairs, big groups and many copies of each id
+    #[test]
+    fn crowded_ids_match_masked() {
+        let mut rng = Rng::new(3);
+        let ids: Vec<String> = (0..150).map(|_| (0..4).map(|_| *rng.pick(&["a", "b", "c"])).collect()).collect();
+        let pairs = external_pairs(&ids, 1);
+        assert!(pairs.len() > 1000);
+        assert_eq!(pairs, masked_pairs(&ids));
+        assert_eq!(external_pairs(&ids, DEFAULT_BUDGET), pairs);
+    }
+}
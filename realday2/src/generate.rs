@@ -0,0 +1,30 @@
+use aoc_common::random::{Options, Rng};
+use std::cmp;
+
+const DEFAULT_IDS: usize = 250;
+const ID_LENGTH: usize = 26;
+
+/*
+    size random box ids of 26 lowercase letters, one per line
+
+    One id is a copy of another with a single letter changed, which is the part 2 pair. Any other two
+    random ids differing in at most one position is vanishingly unlikely (about 1 in 10^33 per pair)
+*/
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let size = cmp::max(options.size.unwrap_or(DEFAULT_IDS), 2);
+
+    let mut ids: Vec<Vec<char>> = (0..size - 1)
+        .map(|_| (0..ID_LENGTH).map(|_| rng.lowercase()).collect())
+        .collect();
+
+    let mut copy = rng.pick(&ids).clone();
+    let position = rng.below(ID_LENGTH as u64) as usize;
+    let original = copy[position];
+    while copy[position] == original {
+        copy[position] = rng.lowercase();
+    }
+    ids.push(copy);
+    rng.shuffle(&mut ids);
+
+    ids.iter().map(|id| id.iter().collect::<String>() + "\n").collect()
+}
@@ -0,0 +1,206 @@
+#[macro_use]
+extern crate aoc_common;
+extern crate unicode_normalization;
+extern crate unicode_segmentation;
+
+use aoc_common::solution;
+use aoc_common::{Answer, Error, Extras, Implementation, Json, ParseError, Result, Solution};
+use profile::Profile;
+use unicode_segmentation::UnicodeSegmentation;
+use std::convert::TryFrom;
+use std::vec::Vec;
+
+pub mod bktree;
+pub mod catalogue;
+pub mod charsum;
+pub mod clusters;
+pub mod edit;
+pub mod external;
+pub mod generate;
+pub mod masked;
+pub mod prefilter;
+pub mod profile;
+pub mod unicode;
+
+// Whether some letter appears exactly two times and whether some letter appears exactly three times
+// (see profile for any other counts)
+pub fn count_2xletter_3xletter(boxid: &str) -> (bool, bool) {
+    let profile = Profile::of(boxid);
+    (profile.has(2), profile.has(3))
+}
+
+// The puzzle's checksum: ids with a letter appearing exactly twice times ids with a letter appearing
+// exactly three times (see profile::checksum_of for other counts)
+pub const CHECKSUM_COUNTS: &[u32] = &[2, 3];
+
+// A checksum over any counts, as a number while it fits in one
+pub fn checksum(boxids: &[String], counts: &[u32]) -> Result<Answer> {
+    let checksum = profile::checksum_of(boxids, counts)
+        .ok_or_else(|| Error::NoAnswer("the checksum does not fit in 128 bits".to_string()))?;
+    Ok(match i64::try_from(checksum) {
+        Ok(n) => n.into(),
+        Err(_) => checksum.to_string().into(),
+    })
+}
+
+/*
+    Problem: We need to find the two words in a set of words such that only differ by 1 letter
+    AT THE SAME POSITION (ccc vs ccd)
+
+    Approach 0: Check every possible pair of words: O(n^2 * k), n = total number of words, k = word length. 
+
+    Approach 1: Sum up their word values and check those that have a difference of only 1
+    Problem: ccc and bce have relative values (0, +1), but differ in 2 locations. 
+    So worst case: we have a lot of words with that exact difference. 
+    Approach 1 is --not significantly better--
+
+
+*/
+pub fn find_common_characters(boxids: &[String]) -> Option<String> {
+    for (i, item) in boxids.iter().enumerate() {
+        if let Some(valid_str) = has_hamming_distance_one(item, &boxids[i+1..]) {
+            info!("Match: \n{}\n{}", valid_str, item);
+            return Some(common_characters(&valid_str, item));
+        }
+    }
+    None
+}
+
+pub fn has_hamming_distance_one(string_to_match: &str, rest_of_strings: &[String]) -> Option<String> {
+    for s in rest_of_strings {
+        if hamming_distance_same_len(string_to_match, s) == 1 {
+            return Some(s.clone());
+        }
+    }
+    None
+}
+
+// Ids are compared character by character (see unicode), and must have as many characters as each other
+pub fn hamming_distance_same_len(x: &str, y: &str) -> u32 {
+    assert_eq!(unicode::length(x), unicode::length(y));
+    hamming_distance(x, y)
+}
+
+// Hamming distance that also works for ids of different lengths: the shorter id counts as padded
+// with characters that match nothing, so the extra length counts as differences. Still a metric,
+// which the BK-tree needs
+pub fn hamming_distance(x: &str, y: &str) -> u32 {
+    if x.is_ascii() && y.is_ascii() {
+        let differences: u32 = x.bytes().zip(y.bytes()).map(|(c1, c2)| if c1 == c2 {0} else {1}).sum();
+        return differences + (x.len() as i64 - y.len() as i64).unsigned_abs() as u32;
+    }
+    let (mut xs, mut ys) = (x.graphemes(true), y.graphemes(true));
+    let mut dist = 0;
+    loop {
+        match (xs.next(), ys.next()) {
+            (None, None) => return dist,
+            (Some(c1), Some(c2)) if c1 == c2 => {}
+            _ => dist += 1,
+        }
+    }
+}
+
+pub fn common_characters(x: &str, y: &str) -> String {
+    x.graphemes(true).zip(y.graphemes(true)).filter(|(c1, c2)| c1 == c2).map(|(c1, _)| c1).collect::<String>()
+}
+
+// The characters every id has at the same position: common_characters for any number of ids
+// (no ids have no characters in common)
+pub fn common_characters_of<S: AsRef<str>>(boxids: &[S]) -> String {
+    let ids: Vec<Vec<&str>> = boxids.iter().map(|id| unicode::characters(id.as_ref())).collect();
+    let (first, rest) = match ids.split_first() {
+        Some(split) => split,
+        None => return String::new(),
+    };
+    first
+        .iter()
+        .enumerate()
+        .filter(|(position, c)| rest.iter().all(|id| id.get(*position) == Some(*c)))
+        .map(|(_, c)| *c)
+        .collect()
+}
+
+// Collecting all strings in the file for futher processing
+// Box ids are compared position by position, so they must all be as long as the first one
+// (in characters, and normalized: see unicode)
+pub fn parse_boxids(input: &str) -> Result<Vec<String>> {
+    let mut boxids: Vec<String> = Vec::new();
+    let mut expected = 0;
+    for (i, line) in input.lines().enumerate() {
+        let boxid = unicode::normalize(line);
+        let found = unicode::length(&boxid);
+        if boxids.is_empty() {
+            expected = found;
+        } else if expected != found {
+            let reason = format!("expected a box id of {} characters, found {}", expected, found);
+            // Columns count code points, so point at the first code point of the character that is one too many
+            let kept = std::cmp::min(expected, found);
+            let column = line.graphemes(true).take(kept).map(|c| c.chars().count()).sum::<usize>() + 1;
+            return Err(ParseError::new(i + 1, column, line, &reason).into());
+        }
+        boxids.push(boxid);
+    }
+    Ok(boxids)
+}
+
+// Box ids of any length, for the edit distance implementations (see edit)
+pub fn parse_any_boxids(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(unicode::normalize).collect())
+}
+
+pub struct Inventory;
+
+// Part 1 by the puzzle's counts (the Solution) or by counts 2, 3 and 4 (see profile)
+// Part 2 by masked position hashing (the Solution), brute force over all pairs, the (unsound) char sum shortcut,
+// comparing only ids that share half their positions, or by edit distance, which also takes ids of different lengths
+pub const IMPLEMENTATIONS: &[Implementation] = &[
+    Implementation { name: "checksum", part: 1, solve: solution::part1::<Inventory> },
+    Implementation { name: "checksum-2-3-4", part: 1, solve: checksum_2_3_4 },
+    Implementation { name: "masked", part: 2, solve: solution::part2::<Inventory> },
+    Implementation { name: "all-pairs", part: 2, solve: all_pairs },
+    Implementation { name: "charsum", part: 2, solve: charsum::part2 },
+    Implementation { name: "blocks", part: 2, solve: prefilter::part2_blocks },
+    Implementation { name: "levenshtein", part: 2, solve: edit::part2_levenshtein },
+    Implementation { name: "damerau", part: 2, solve: edit::part2_damerau },
+];
+
+fn checksum_2_3_4(input: &str) -> Result<Answer> {
+    checksum(&parse_boxids(input)?, &[2, 3, 4])
+}
+
+fn all_pairs(input: &str) -> Result<Answer> {
+    find_common_characters(&parse_boxids(input)?)
+        .map(Answer::from)
+        .ok_or_else(|| Error::NoAnswer("no two box ids differ by exactly one character".to_string()))
+}
+
+impl Solution for Inventory {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_boxids(input)
+    }
+
+    fn part1(boxids: &Vec<String>) -> Result<Answer> {
+        checksum(boxids, CHECKSUM_COUNTS)
+    }
+
+    fn part2(boxids: &Vec<String>) -> Result<Answer> {
+        masked::common_characters_of_first_pair(boxids)
+    }
+
+    // Part 1 also reports how many ids have some letter appearing exactly n times, for every n, and
+    // part 2 how many families of near-duplicate ids there are (see clusters)
+    fn extras(boxids: &Vec<String>, part: u32) -> Result<Extras> {
+        if part != 1 {
+            let groups = clusters::groups(boxids);
+            let largest = groups.iter().map(|group| group.ids.len()).max().unwrap_or(0);
+            return Ok(vec![("groups", groups.len().into()), ("largest_group", largest.into())]);
+        }
+        let ids = profile::ids_by_count(boxids)
+            .into_iter()
+            .map(|(count, ids)| (count.to_string(), ids.into()))
+            .collect();
+        Ok(vec![("ids_with_count", Json::Object(ids))])
+    }
+}
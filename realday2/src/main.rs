@@ -1,61 +1,52 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Result};
-use std::vec::Vec;
+#[macro_use]
+extern crate aoc_common;
+extern crate realday2;
 
-const INPUT_FILENAME: &str = "input.txt";
-/*
-    Problem: We need to find the two words in a set of words such that only differ by 1 letter
-    AT THE SAME POSITION (ccc vs ccd)
-
-    Approach 0: Check every possible pair of words: O(n^2 * k), n = total number of words, k = word length. 
-
-    Approach 1: Sum up their word values and check those that have a difference of only 1
-    Problem: ccc and bce have relative values (0, +1), but differ in 2 locations. 
-    So worst case: we have a lot of words with that exact difference. 
-    Approach 1 is --not significantly better--
+use aoc_common::log::{self, Level};
+use aoc_common::{input, Result, Solution};
+use realday2::Inventory;
 
+const INPUT_FILENAME: &str = "input.txt";
 
-*/
 fn main() -> Result<()> {
-    println!("Reading AOC Day 2 Input file: input.txt");
-
-    // include_str! is also viable here, but wanted to learn File operations explicitly
-    let f = File::open(INPUT_FILENAME)?;
+    let sources = input::Sources::from_args(input::default_path(INPUT_FILENAME, env!("CARGO_MANIFEST_DIR")))?;
+    info!("Reading AOC Day 2 Input: {}", sources.names());
 
-    let mut boxids: Vec<String> = Vec::new();
-
-    // Collecting all strings in the file for futher processing 
-    for line in BufReader::new(f).lines() {
-        let line_parse = line.unwrap();
-        boxids.push(line_parse.clone());
+    let boxids = Inventory::parse(&sources.text).map_err(|e| sources.locate(e))?;
+    answer!("Checksum: {}", Inventory::part1(&boxids)?);
+    for (count, ids) in realday2::profile::ids_by_count(&boxids) {
+        info!("Ids with a letter appearing exactly {} times: {}", count, ids);
     }
-
-    for (i, item) in boxids.iter().enumerate() {
-        if let Some(valid_str) = has_hamming_distance_one(&item, &boxids[i+1..]) {
-            println!("Match: \n{}\n{}", valid_str, item);
-            println!("Common characters: {}", common_characters(&valid_str, item))
+    answer!("Common characters: {}", Inventory::part2(&boxids)?);
+    // Everything below only reports on how the answers were reached, and the brute force is O(n^2)
+    if log::enabled(Level::Info) {
+        let pairs = realday2::prefilter::distance_one_pairs(&boxids);
+        for prefilter in realday2::prefilter::PREFILTERS {
+            let recall = realday2::prefilter::recall(&boxids, *prefilter, &pairs);
+            info!(
+                "{} prefilter: {} candidates, {} of {} pairs at distance one (recall {:.1}%)",
+                prefilter.name(),
+                recall.candidates,
+                recall.found,
+                recall.pairs,
+                recall.ratio() * 100.0
+            );
         }
     }
-
-
-    Ok(())
-}
-
-fn has_hamming_distance_one(string_to_match: &str, rest_of_strings: &[String]) -> Option<String> {
-    for s in rest_of_strings {
-        if hamming_distance_same_len(string_to_match, s) == 1 {
-            return Some(s.clone());
+    if log::enabled(Level::Trace) {
+        for pair in realday2::masked::distance_one_pairs(&boxids) {
+            trace!("Differ only at position {}:\n{}\n{}", pair.position, boxids[pair.first], boxids[pair.second]);
+        }
+    }
+    if log::enabled(Level::Info) {
+        for group in realday2::clusters::groups(&boxids) {
+            let positions: Vec<String> = group.positions.iter().map(|p| p.to_string()).collect();
+            info!("Group of {} differing at positions {}:", group.ids.len(), positions.join(", "));
+            for id in group.ids {
+                info!("{}", boxids[id]);
+            }
         }
     }
-    None
-}
-
-fn hamming_distance_same_len(x: &str, y: &str) -> u32 {
-    assert_eq!(x.len(), y.len());
-    let dist: u32 = x.chars().zip(y.chars()).map(|(c1, c2)| if c1 == c2 {0} else {1}).sum();
-    dist
-}
 
-fn common_characters(x: &str, y: &str) -> String {
-    x.chars().zip(y.chars()).filter(|(c1, c2)| c1 == c2).map(|(c1, _)| c1).collect::<String>()
+    Ok(())
 }
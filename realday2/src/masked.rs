@@ -0,0 +1,139 @@
+use aoc_common::{Answer, Error, Result};
+use std::collections::HashMap;
+
+use unicode;
+use parse_boxids;
+
+/*
+    Approach 2: masked positions
+
+    Two ids differ in exactly one position p iff they are equal once position p is wildcarded.
+    So for each position, bucket the ids by their hash with that position masked out and only
+    compare ids that share a bucket.
+
+    The hash is sum(c[q] * B^q) (wrapping), so masking position p is just subtracting c[p] * B^p:
+    O(1) per id and position, O(n * k) overall plus O(k) to confirm each candidate pair
+    (hash collisions are checked, not trusted)
+*/
+const BASE: u64 = 0x100_0000_01b3;
+
+// Two box ids (by index, first < second) that differ only at position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NearPair {
+    pub first: usize,
+    pub second: usize,
+    pub position: usize,
+}
+
+// Every pair of box ids differing in exactly one position, sorted by first and then second id
+pub fn distance_one_pairs(boxids: &[String]) -> Vec<NearPair> {
+    let ids = unicode::intern(boxids);
+    let k = ids.first().map_or(0, |id| id.len());
+
+    let mut powers: Vec<u64> = Vec::with_capacity(k);
+    let mut power: u64 = 1;
+    for _ in 0..k {
+        powers.push(power);
+        power = power.wrapping_mul(BASE);
+    }
+    let hashes: Vec<u64> = ids
+        .iter()
+        .map(|id| {
+            id.iter()
+                .zip(&powers)
+                .fold(0u64, |h, (c, p)| h.wrapping_add((*c as u64).wrapping_mul(*p)))
+        })
+        .collect();
+
+    // For each position, the first id seen with each masked hash, and every later id that shares
+    // a masked hash with an earlier one (as (first id, later id)). Buckets with a single id, which are
+    // nearly all of them, then never allocate
+    let mut pairs: Vec<NearPair> = Vec::new();
+    let mut firsts: HashMap<u64, usize> = HashMap::with_capacity(ids.len());
+    let mut shared: Vec<(usize, usize)> = Vec::new();
+    for position in 0..k {
+        firsts.clear();
+        shared.clear();
+        for (i, id) in ids.iter().enumerate() {
+            let masked = hashes[i].wrapping_sub((id[position] as u64).wrapping_mul(powers[position]));
+            match firsts.get(&masked) {
+                Some(&first) => shared.push((first, i)),
+                None => {
+                    firsts.insert(masked, i);
+                }
+            }
+        }
+        shared.sort();
+        for bucket in shared.chunk_by(|x, y| x.0 == y.0) {
+            let members: Vec<usize> = Some(bucket[0].0).into_iter().chain(bucket.iter().map(|(_, i)| *i)).collect();
+            for (a, &first) in members.iter().enumerate() {
+                for &second in &members[a + 1..] {
+                    if equal_except(&ids[first], &ids[second], position) {
+                        pairs.push(NearPair { first, second, position });
+                    }
+                }
+            }
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+// Whether x and y differ at position and nowhere else
+fn equal_except(x: &[u32], y: &[u32], position: usize) -> bool {
+    x[position] != y[position] && x[..position] == y[..position] && x[position + 1..] == y[position + 1..]
+}
+
+// The common characters of the first pair, the same pair the all-pairs search finds
+pub fn part2(input: &str) -> Result<Answer> {
+    let boxids = parse_boxids(input)?;
+    common_characters_of_first_pair(&boxids)
+}
+
+pub fn common_characters_of_first_pair(boxids: &[String]) -> Result<Answer> {
+    let pair = distance_one_pairs(boxids)
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::NoAnswer("no two box ids differ by exactly one character".to_string()))?;
+    let common: String = unicode::characters(&boxids[pair.first])
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| *i != pair.position)
+        .map(|(_, c)| c)
+        .collect();
+    Ok(common.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{distance_one_pairs, NearPair};
+    use aoc_common::random::Rng;
+    use unicode;
+
+    // Every pair against every other, position by position
+    fn all_pairs(boxids: &[String]) -> Vec<NearPair> {
+        let ids: Vec<Vec<&str>> = boxids.iter().map(|id| unicode::characters(id)).collect();
+        let mut pairs: Vec<NearPair> = Vec::new();
+        for first in 0..ids.len() {
+            for second in first + 1..ids.len() {
+                let differ: Vec<usize> = (0..ids[first].len()).filter(|&p| ids[first][p] != ids[second][p]).collect();
+                if let [position] = differ[..] {
+                    pairs.push(NearPair { first, second, position });
+                }
+            }
+        }
+        pairs
+    }
+
+    // Few characters (one of them a flag, several code points) and short ids, so that there are plenty
+    // of pairs, copies and hash buckets with more than two ids
+    #[test]
+    fn pairs_match_all_pairs() {
+        let mut rng = Rng::new(5);
+        for len in 0..6 {
+            let boxids: Vec<String> =
+                (0..120).map(|_| (0..len).map(|_| *rng.pick(&["a", "b", "c", "\u{1f1eb}\u{1f1f7}"])).collect()).collect();
+            assert_eq!(distance_one_pairs(&boxids), all_pairs(&boxids), "ids of length {}", len);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_common::{Answer, Error, Result};
+use std::collections::HashMap;
+
+use {charsum, common_characters, hamming_distance_same_len, parse_boxids, unicode};
+
+/*
+    Candidate generators: cheap ways of picking which pairs of ids are worth comparing
+
+    CharSum is approach 1 from the top of lib.rs (see charsum): sort by the sum of the character values
+    and only pair up neighbours whose sums differ by exactly one. It is not sound: ids differing in one
+    position can have sums differing by anything (abc vs abz), and non-neighbours are never paired.
+
+    Blocks(n) cuts the positions into n runs of (nearly) equal length and buckets the ids by the
+    contents of each run, pairing up ids that share any bucket. Ids that differ in fewer than n
+    positions must agree on at least one whole run (pigeonhole), so Blocks(2) finds every pair at
+    distance one, and Blocks(d + 1) every pair at distance d or less. Buckets of random ids are tiny,
+    so hardly anything else gets compared.
+
+    recall measures a generator against comparing every pair (distance_one_pairs)
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prefilter {
+    CharSum,
+    Blocks(usize),
+}
+
+pub const DEFAULT_BLOCKS: usize = 2;
+
+pub const PREFILTERS: &[Prefilter] = &[Prefilter::CharSum, Prefilter::Blocks(DEFAULT_BLOCKS)];
+
+impl Prefilter {
+    pub fn name(self) -> String {
+        match self {
+            Prefilter::CharSum => "charsum".to_string(),
+            Prefilter::Blocks(blocks) => format!("blocks({})", blocks),
+        }
+    }
+}
+
+// Candidate pairs (first < second), sorted and without repeats
+pub fn candidates(boxids: &[String], prefilter: Prefilter) -> Vec<(usize, usize)> {
+    let mut pairs = match prefilter {
+        Prefilter::CharSum => char_sum_neighbours(boxids),
+        Prefilter::Blocks(blocks) => shared_blocks(boxids, blocks),
+    };
+    pairs.sort();
+    pairs.dedup();
+    pairs
+}
+
+fn char_sum_neighbours(boxids: &[String]) -> Vec<(usize, usize)> {
+    let mut sums: Vec<(u64, usize)> = charsum::char_sums(boxids).into_iter().zip(0..).collect();
+    sums.sort();
+    sums.windows(2)
+        .filter(|pair| pair[1].0 - pair[0].0 == 1)
+        .map(|pair| (pair[0].1.min(pair[1].1), pair[0].1.max(pair[1].1)))
+        .collect()
+}
+
+fn shared_blocks(boxids: &[String], blocks: usize) -> Vec<(usize, usize)> {
+    let ids = unicode::intern(boxids);
+    let blocks = blocks.max(1);
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut buckets: HashMap<&[u32], Vec<usize>> = HashMap::new();
+    for block in 0..blocks {
+        buckets.clear();
+        for (i, id) in ids.iter().enumerate() {
+            let (start, end) = (id.len() * block / blocks, id.len() * (block + 1) / blocks);
+            buckets.entry(&id[start..end]).or_default().push(i);
+        }
+        for members in buckets.values() {
+            for (a, &first) in members.iter().enumerate() {
+                pairs.extend(members[a + 1..].iter().map(|&second| (first, second)));
+            }
+        }
+    }
+    pairs
+}
+
+// How many of the pairs at distance one a generator finds, and how much it makes us compare to find them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recall {
+    pub candidates: usize,
+    pub found: usize,
+    pub pairs: usize,
+}
+
+impl Recall {
+    // 1 when there is nothing to find
+    pub fn ratio(&self) -> f64 {
+        if self.pairs == 0 {
+            1.0
+        } else {
+            self.found as f64 / self.pairs as f64
+        }
+    }
+}
+
+// Every pair (first < second) at distance one, sorted, found by comparing every pair
+pub fn distance_one_pairs(boxids: &[String]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for (i, first) in boxids.iter().enumerate() {
+        pairs.extend(
+            boxids[i + 1..]
+                .iter()
+                .enumerate()
+                .filter(|(_, second)| hamming_distance_same_len(first, second) == 1)
+                .map(|(j, _)| (i, i + 1 + j)),
+        );
+    }
+    pairs
+}
+
+// Checks a generator's candidates against the pairs from distance_one_pairs, which can be shared between generators
+pub fn recall(boxids: &[String], prefilter: Prefilter, pairs: &[(usize, usize)]) -> Recall {
+    let candidates = candidates(boxids, prefilter);
+    let found = candidates.iter().filter(|pair| pairs.binary_search(pair).is_ok()).count();
+    Recall { candidates: candidates.len(), found, pairs: pairs.len() }
+}
+
+// The common characters of the first pair at distance one, comparing only Blocks candidates
+pub fn part2_blocks(input: &str) -> Result<Answer> {
+    let boxids = parse_boxids(input)?;
+    candidates(&boxids, Prefilter::Blocks(DEFAULT_BLOCKS))
+        .into_iter()
+        .find(|&(first, second)| hamming_distance_same_len(&boxids[first], &boxids[second]) == 1)
+        .map(|(first, second)| common_characters(&boxids[first], &boxids[second]).into())
+        .ok_or_else(|| Error::NoAnswer("no two box ids differ by exactly one character".to_string()))
+}
@@ -0,0 +1,63 @@
+use std::collections::{BTreeMap, HashMap};
+use unicode_segmentation::UnicodeSegmentation;
+
+/*
+    Letter frequency profile of a box id: for every count, how many different letters appear exactly
+    that many times (aabbbc has one letter twice, one three times and one once: {1: 1, 2: 1, 3: 1})
+
+    The puzzle checksum only asks about counts 2 and 3, but any set of counts works the same way:
+    count the ids with some letter appearing exactly c times for each c, and multiply
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    classes: BTreeMap<u32, u32>,
+}
+
+impl Profile {
+    pub fn of(boxid: &str) -> Profile {
+        let mut letters: HashMap<&str, u32> = HashMap::new();
+        for c in boxid.graphemes(true) {
+            *letters.entry(c).or_insert(0) += 1;
+        }
+        let mut classes: BTreeMap<u32, u32> = BTreeMap::new();
+        for count in letters.values() {
+            *classes.entry(*count).or_insert(0) += 1;
+        }
+        Profile { classes }
+    }
+
+    // Whether some letter appears exactly count times
+    pub fn has(&self, count: u32) -> bool {
+        self.classes.contains_key(&count)
+    }
+
+    // How many letters appear exactly count times
+    pub fn letters_with(&self, count: u32) -> u32 {
+        self.classes.get(&count).cloned().unwrap_or(0)
+    }
+
+    // (count, letters appearing exactly that many times), by increasing count
+    pub fn classes(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.classes.iter().map(|(count, letters)| (*count, *letters))
+    }
+}
+
+// For every count, the number of ids with some letter appearing exactly that many times
+pub fn ids_by_count<S: AsRef<str>>(boxids: &[S]) -> BTreeMap<u32, usize> {
+    let mut ids: BTreeMap<u32, usize> = BTreeMap::new();
+    for boxid in boxids {
+        for (count, _) in Profile::of(boxid.as_ref()).classes() {
+            *ids.entry(count).or_insert(0) += 1;
+        }
+    }
+    ids
+}
+
+// Product over counts of the number of ids with some letter appearing exactly that many times
+// (the puzzle's checksum is counts 2 and 3). None if it does not fit in a u128
+pub fn checksum_of<S: AsRef<str>>(boxids: &[S], counts: &[u32]) -> Option<u128> {
+    let ids = ids_by_count(boxids);
+    counts.iter().try_fold(1u128, |product, count| {
+        product.checked_mul(ids.get(count).cloned().unwrap_or(0) as u128)
+    })
+}
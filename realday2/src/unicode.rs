@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/*
+    What a "character" of a box id is
+
+    Ids are compared one user-perceived character (extended grapheme cluster) at a time, after
+    normalizing them to NFC: é written as one code point or as e plus a combining accent is the same
+    single character, and a flag or an emoji with modifiers is one character rather than several.
+    Byte lengths (str::len) and code points (chars) never count as characters.
+
+    parse_boxids and parse_any_boxids normalize the ids they return, so anything else compared against
+    them (like a query id) has to go through normalize as well
+*/
+pub fn normalize(id: &str) -> String {
+    if id.is_ascii() {
+        id.to_string()
+    } else {
+        id.nfc().collect()
+    }
+}
+
+pub fn characters(id: &str) -> Vec<&str> {
+    id.graphemes(true).collect()
+}
+
+pub fn length(id: &str) -> usize {
+    if id.is_ascii() {
+        id.len()
+    } else {
+        id.graphemes(true).count()
+    }
+}
+
+// Gives every character a small number, equal numbers for equal characters (in the order characters
+// are first seen)
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    numbers: HashMap<String, u32>,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    pub fn intern(&mut self, id: &str) -> Vec<u32> {
+        id.graphemes(true)
+            .map(|c| match self.numbers.get(c) {
+                Some(&number) => number,
+                None => {
+                    let number = self.numbers.len() as u32;
+                    self.numbers.insert(c.to_string(), number);
+                    number
+                }
+            })
+            .collect()
+    }
+
+    // The number of a character seen before
+    pub fn number(&self, c: &str) -> Option<u32> {
+        self.numbers.get(c).cloned()
+    }
+}
+
+// Every id as a sequence of small numbers, one per character (see Interner)
+pub fn intern<S: AsRef<str>>(ids: &[S]) -> Vec<Vec<u32>> {
+    let mut interner = Interner::new();
+    ids.iter().map(|id| interner.intern(id.as_ref())).collect()
+}